@@ -0,0 +1,458 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::{Answer, InputError, Variant};
+
+/// The known answers, kept in answers.toml next to Cargo.toml:
+///
+/// ```toml
+/// [day01.part1]
+/// example = 3
+/// real = 1180
+/// # submitted answers the server rejected
+/// wrong = [1179]
+/// too_high = 2000
+/// too_low = 1000
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    parts: BTreeMap<(u32, u32), Part>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Part {
+    answers: BTreeMap<Variant, Answer>,
+    wrong: Vec<Answer>,
+    // smallest answer known to be too high and largest one known to be too low
+    too_high: Option<u64>,
+    too_low: Option<u64>,
+}
+
+/// Why the server rejected a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rejection::Wrong => write!(f, "wrong"),
+            Rejection::TooHigh => write!(f, "too high"),
+            Rejection::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// Outcome of comparing a computed answer with answers.toml.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong {
+        expected: Answer,
+    },
+    /// The answer is not in answers.toml yet, but the server already rejected it.
+    Rejected(Rejection),
+    /// The answer is not in answers.toml yet.
+    New,
+    /// Nothing was computed, although answers.toml has an answer.
+    Missing {
+        expected: Answer,
+    },
+    /// Nothing was computed and nothing is expected.
+    Unsolved,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Check::Correct => write!(f, "correct"),
+            Check::Wrong { expected } => write!(f, "wrong, expected {}", expected),
+            Check::Rejected(rejection) => write!(f, "already rejected as {}", rejection),
+            Check::New => write!(f, "new"),
+            Check::Missing { expected } => write!(f, "missing, expected {}", expected),
+            Check::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl Answers {
+    pub const FILE_NAME: &str = "answers.toml";
+
+    const HEADER: &str = "\
+# Known puzzle answers, checked by the runner and by tests/answers.rs.
+# Keyed by day and part, with one answer per input variant: real, example or example2.
+# wrong, too_high and too_low record the submissions of the real answer that were rejected.
+";
+
+    /// Path to answers.toml of this crate, independent of the working directory.
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(Answers::FILE_NAME)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers, InputError> {
+        let path = path.as_ref();
+        let text =
+            std::fs::read_to_string(path).map_err(|e| InputError::io(path.to_path_buf(), e))?;
+        Answers::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, InputError> {
+        let layout: Layout = toml::from_str(text).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+            InputError::parse(
+                text[..offset].matches('\n').count(),
+                text[line_start..offset].chars().count(),
+                e.message(),
+            )
+        })?;
+
+        let mut answers = Answers::default();
+        for (DayKey(day), parts) in layout {
+            for (PartKey(part), table) in parts {
+                answers.parts.insert((day, part), table.into());
+            }
+        }
+        Ok(answers)
+    }
+
+    /// The answers as the content of answers.toml.
+    pub fn to_toml(&self) -> String {
+        let mut layout = Layout::new();
+        for (&(day, part), entry) in &self.parts {
+            layout
+                .entry(DayKey(day))
+                .or_default()
+                .insert(PartKey(part), entry.into());
+        }
+        let body = toml::to_string(&layout).expect("answers are valid TOML");
+        format!("{}\n{}", Answers::HEADER, body)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        std::fs::write(path, self.to_toml())
+    }
+
+    pub fn get(&self, day: u32, part: u32, variant: Variant) -> Option<&Answer> {
+        self.parts.get(&(day, part))?.answers.get(&variant)
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, variant: Variant, answer: Answer) {
+        let entry = self.parts.entry((day, part)).or_default();
+        entry.answers.insert(variant, answer);
+    }
+
+    /// All answers, ordered by day, part and variant.
+    pub fn iter(&self) -> impl Iterator<Item = ((u32, u32, Variant), &Answer)> {
+        self.parts.iter().flat_map(|(&(day, part), entry)| {
+            entry
+                .answers
+                .iter()
+                .map(move |(&variant, answer)| ((day, part, variant), answer))
+        })
+    }
+
+    /// Records that the server rejected `answer` for the real input.
+    pub fn reject(&mut self, day: u32, part: u32, answer: &Answer, rejection: Rejection) {
+        let entry = self.parts.entry((day, part)).or_default();
+        match (rejection, answer) {
+            (Rejection::TooHigh, &Answer::Number(n)) => {
+                entry.too_high = Some(entry.too_high.map_or(n, |high| high.min(n)));
+            }
+            (Rejection::TooLow, &Answer::Number(n)) => {
+                entry.too_low = Some(entry.too_low.map_or(n, |low| low.max(n)));
+            }
+            _ if !entry.wrong.contains(answer) => entry.wrong.push(answer.clone()),
+            _ => {}
+        }
+    }
+
+    /// Whether `answer` for the real input is known to be wrong, from earlier rejected submissions.
+    pub fn rejection(&self, day: u32, part: u32, answer: &Answer) -> Option<Rejection> {
+        let entry = self.parts.get(&(day, part))?;
+        if entry.wrong.contains(answer) {
+            return Some(Rejection::Wrong);
+        }
+        let &Answer::Number(n) = answer else {
+            return None;
+        };
+        if entry.too_high.is_some_and(|high| n >= high) {
+            Some(Rejection::TooHigh)
+        } else if entry.too_low.is_some_and(|low| n <= low) {
+            Some(Rejection::TooLow)
+        } else {
+            None
+        }
+    }
+
+    pub fn check(&self, day: u32, part: u32, variant: Variant, answer: &Answer) -> Check {
+        let rejection = match variant {
+            Variant::Real => self.rejection(day, part, answer),
+            _ => None,
+        };
+        match (self.get(day, part, variant), answer) {
+            (Some(expected), Answer::Unsolved) => Check::Missing {
+                expected: expected.clone(),
+            },
+            (None, Answer::Unsolved) => Check::Unsolved,
+            (Some(expected), _) if expected == answer => Check::Correct,
+            (Some(expected), _) => Check::Wrong {
+                expected: expected.clone(),
+            },
+            (None, _) => rejection.map_or(Check::New, Check::Rejected),
+        }
+    }
+}
+
+// answers.toml as (de)serialized, the keys check their own format so errors point into the file
+type Layout = BTreeMap<DayKey, BTreeMap<PartKey, PartTable>>;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct DayKey(u32);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct PartKey(u32);
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartTable {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example2: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    real: Option<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_high: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_low: Option<u64>,
+}
+
+impl From<PartTable> for Part {
+    fn from(table: PartTable) -> Self {
+        let answers = [
+            (Variant::Example, table.example),
+            (Variant::Example2, table.example2),
+            (Variant::Real, table.real),
+        ];
+        Part {
+            answers: answers
+                .into_iter()
+                .filter_map(|(variant, value)| Some((variant, value?.into())))
+                .collect(),
+            wrong: table.wrong.into_iter().map(Answer::from).collect(),
+            too_high: table.too_high,
+            too_low: table.too_low,
+        }
+    }
+}
+
+impl From<&Part> for PartTable {
+    fn from(entry: &Part) -> Self {
+        let answer = |variant| entry.answers.get(&variant).map(Value::from);
+        PartTable {
+            example: answer(Variant::Example),
+            example2: answer(Variant::Example2),
+            real: answer(Variant::Real),
+            wrong: entry.wrong.iter().map(Value::from).collect(),
+            too_high: entry.too_high,
+            too_low: entry.too_low,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DayKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        key.strip_prefix("day")
+            .and_then(|day| day.parse().ok())
+            .map(DayKey)
+            .ok_or_else(|| {
+                de::Error::custom(format!("expected a day like 'day01', found '{}'", key))
+            })
+    }
+}
+
+impl Serialize for DayKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("day{:02}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for PartKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        match key.as_str() {
+            "part1" => Ok(PartKey(1)),
+            "part2" => Ok(PartKey(2)),
+            _ => Err(de::Error::custom(format!(
+                "expected 'part1' or 'part2', found '{}'",
+                key
+            ))),
+        }
+    }
+}
+
+impl Serialize for PartKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("part{}", self.0))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(u64),
+    Text(String),
+}
+
+impl From<Value> for Answer {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(n) => Answer::Number(n),
+            // numbers beyond the range of TOML integers are written as strings
+            Value::Text(s) => s.parse().map_or(Answer::Text(s), Answer::Number),
+        }
+    }
+}
+
+impl From<&Answer> for Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            &Answer::Number(n) if i64::try_from(n).is_ok() => Value::Number(n),
+            answer => Value::Text(answer.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Check, Rejection};
+    use crate::advent_of_code::{Answer, InputError, Variant};
+
+    const TEXT: &str = "
+[day01.part1]
+example = 3
+real = 1180
+
+[day02.part2]
+example2 = \"18446744073709551615\"
+real = \"abc\"
+wrong = [\"abd\", 17]
+too_high = 100
+too_low = 10
+";
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse(TEXT).unwrap();
+        assert_eq!(
+            answers.get(1, 1, Variant::Real),
+            Some(&Answer::Number(1180))
+        );
+        assert_eq!(
+            answers.get(2, 2, Variant::Example2),
+            Some(&Answer::Number(u64::MAX))
+        );
+        assert_eq!(answers.get(2, 2, Variant::Real), Some(&Answer::from("abc")));
+        assert_eq!(answers.get(2, 1, Variant::Real), None);
+        assert_eq!(answers.iter().count(), 4);
+    }
+
+    #[test]
+    fn parse_reports_position() {
+        let err = Answers::parse("[day01.part1]\nexample = 3\n[day01.part3]\n").unwrap_err();
+        assert!(matches!(err, InputError::Parse { line: 3, .. }), "{}", err);
+        assert!(err.to_string().contains("expected 'part1' or 'part2'"));
+
+        let err = Answers::parse("[day01.part1]\nexampel = 3\n").unwrap_err();
+        assert!(
+            matches!(
+                err,
+                InputError::Parse {
+                    line: 2,
+                    column: 1,
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn to_toml_round_trip() {
+        let answers = Answers::parse(TEXT).unwrap();
+        let text = answers.to_toml();
+        assert!(
+            text.contains("[day01.part1]\nexample = 3\nreal = 1180\n"),
+            "{}",
+            text
+        );
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejected_answers() {
+        let mut answers = Answers::parse(TEXT).unwrap();
+        let rejection = |answers: &Answers, n: u64| answers.rejection(2, 2, &n.into());
+        assert_eq!(rejection(&answers, 17), Some(Rejection::Wrong));
+        assert_eq!(rejection(&answers, 100), Some(Rejection::TooHigh));
+        assert_eq!(rejection(&answers, 10), Some(Rejection::TooLow));
+        assert_eq!(rejection(&answers, 50), None);
+
+        answers.reject(2, 2, &Answer::Number(50), Rejection::TooHigh);
+        answers.reject(2, 2, &Answer::Number(200), Rejection::TooHigh);
+        answers.reject(2, 2, &Answer::Number(20), Rejection::TooLow);
+        answers.reject(2, 2, &Answer::Number(30), Rejection::Wrong);
+        answers.reject(2, 2, &Answer::Number(30), Rejection::Wrong);
+        assert_eq!(rejection(&answers, 60), Some(Rejection::TooHigh));
+        assert_eq!(rejection(&answers, 15), Some(Rejection::TooLow));
+        assert_eq!(rejection(&answers, 30), Some(Rejection::Wrong));
+        assert_eq!(rejection(&answers, 25), None);
+        assert!(answers.to_toml().contains("wrong = [\"abd\", 17, 30]"));
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse(TEXT).unwrap();
+        let check = |part, variant, answer: Answer| answers.check(1, part, variant, &answer);
+        assert_eq!(check(1, Variant::Real, 1180u64.into()), Check::Correct);
+        assert_eq!(
+            check(1, Variant::Real, 1181u64.into()),
+            Check::Wrong {
+                expected: Answer::Number(1180)
+            }
+        );
+        assert_eq!(check(2, Variant::Real, 7u64.into()), Check::New);
+        assert_eq!(
+            check(1, Variant::Example, Answer::Unsolved),
+            Check::Missing {
+                expected: Answer::Number(3)
+            }
+        );
+        assert_eq!(
+            check(2, Variant::Example, Answer::Unsolved),
+            Check::Unsolved
+        );
+
+        let mut answers = Answers::default();
+        answers.reject(3, 1, &Answer::Number(12), Rejection::TooLow);
+        let answer = Answer::Number(11);
+        assert_eq!(
+            answers.check(3, 1, Variant::Real, &answer),
+            Check::Rejected(Rejection::TooLow)
+        );
+        assert_eq!(answers.check(3, 1, Variant::Example, &answer), Check::New);
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{Grid, Neighbourhood, Point};
+
+/// When the new state of a cell becomes visible to the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Update {
+    /// All cells of a generation see the previous generation.
+    #[default]
+    Synchronous,
+    /// Cells are updated in place in row-major order, later cells see the changes of earlier ones.
+    Asynchronous,
+}
+
+/// Why a run of an `Automaton` ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// A generation changed nothing.
+    Fixpoint,
+    /// The grid after generation `first + period` is the same as after generation `first`.
+    Cycle { first: usize, period: usize },
+    /// The generation limit was reached.
+    Limit,
+}
+
+/// Result of `Automaton::run`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run<T> {
+    pub grid: Grid<T>,
+    /// Number of changed cells of every generation that changed something.
+    pub changes: Vec<usize>,
+    pub stop: Stop,
+}
+
+impl<T> Run<T> {
+    pub fn generations(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn total_changes(&self) -> usize {
+        self.changes.iter().sum()
+    }
+}
+
+/// A cell and its neighbourhood, as seen by the rule of an `Automaton`.
+pub struct Context<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+    hood: Neighbourhood,
+}
+
+impl<'a, T> Context<'a, T> {
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    pub fn cell(&self) -> &'a T {
+        &self.grid[self.point]
+    }
+
+    pub fn neighbours(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let grid = self.grid;
+        grid.neighbours(self.point, self.hood)
+            .map(move |n| &grid[n])
+    }
+
+    /// Number of neighbours for which `f` holds.
+    pub fn count<F>(&self, f: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.neighbours().filter(|cell| f(cell)).count()
+    }
+}
+
+/// Runs a per-cell rule over a grid generation by generation.
+///
+/// The rule returns the new state of a cell, or `None` to keep it. Only cells next to a change of
+/// the last generation are evaluated again, so the rule must only look at the cell and its
+/// neighbourhood.
+pub struct Automaton<R> {
+    hood: Neighbourhood,
+    rule: R,
+    update: Update,
+    limit: Option<usize>,
+}
+
+impl<R> Automaton<R> {
+    pub fn new(hood: Neighbourhood, rule: R) -> Self {
+        Automaton {
+            hood,
+            rule,
+            update: Update::default(),
+            limit: None,
+        }
+    }
+
+    pub fn update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Stops after `generations` generations.
+    pub fn limit(mut self, generations: usize) -> Self {
+        self.limit = Some(generations);
+        self
+    }
+
+    /// Runs until a generation changes nothing or the limit is reached.
+    pub fn run<T>(self, grid: Grid<T>) -> Run<T>
+    where
+        T: PartialEq,
+        R: FnMut(&Context<T>) -> Option<T>,
+    {
+        self.run_until(grid, |_, _| None)
+    }
+
+    /// Like `run`, and stops when the grid repeats an earlier state, at the cost of keeping every
+    /// generation.
+    pub fn run_detecting_cycles<T>(self, grid: Grid<T>) -> Run<T>
+    where
+        T: Clone + Eq + Hash,
+        R: FnMut(&Context<T>) -> Option<T>,
+    {
+        let mut seen = HashMap::from([(grid.clone(), 0)]);
+        self.run_until(grid, move |grid, generation| match seen.get(grid) {
+            Some(&first) => Some(first),
+            None => {
+                seen.insert(grid.clone(), generation);
+                None
+            }
+        })
+    }
+
+    // `repeated` sees the grid after every generation that changed something, and returns the
+    // earlier generation it repeats
+    fn run_until<T, F>(mut self, mut grid: Grid<T>, mut repeated: F) -> Run<T>
+    where
+        T: PartialEq,
+        R: FnMut(&Context<T>) -> Option<T>,
+        F: FnMut(&Grid<T>, usize) -> Option<usize>,
+    {
+        let mut frontier: Vec<Point> = grid.iter().map(|(p, _)| p).collect();
+        let mut queued = Grid::filled(grid.rows(), grid.cols(), false);
+        let mut changes = Vec::new();
+
+        loop {
+            if self.limit.is_some_and(|limit| changes.len() >= limit) {
+                return Run {
+                    grid,
+                    changes,
+                    stop: Stop::Limit,
+                };
+            }
+            let changed = match self.update {
+                Update::Synchronous => self.step_synchronous(&mut grid, &frontier),
+                Update::Asynchronous => self.step_asynchronous(&mut grid, &frontier),
+            };
+            if changed.is_empty() {
+                return Run {
+                    grid,
+                    changes,
+                    stop: Stop::Fixpoint,
+                };
+            }
+            changes.push(changed.len());
+
+            let generation = changes.len();
+            if let Some(first) = repeated(&grid, generation) {
+                let stop = Stop::Cycle {
+                    first,
+                    period: generation - first,
+                };
+                return Run {
+                    grid,
+                    changes,
+                    stop,
+                };
+            }
+
+            // the next generation only needs to look at the changed cells and their neighbours
+            frontier.clear();
+            for p in changed {
+                for n in std::iter::once(p).chain(grid.neighbours(p, self.hood)) {
+                    if !std::mem::replace(&mut queued[n], true) {
+                        frontier.push(n);
+                    }
+                }
+            }
+            for &p in &frontier {
+                queued[p] = false;
+            }
+            frontier.sort_unstable();
+        }
+    }
+
+    fn step_synchronous<T>(&mut self, grid: &mut Grid<T>, frontier: &[Point]) -> Vec<Point>
+    where
+        T: PartialEq,
+        R: FnMut(&Context<T>) -> Option<T>,
+    {
+        let updates: Vec<(Point, T)> = frontier
+            .iter()
+            .filter_map(|&point| {
+                let context = Context {
+                    grid,
+                    point,
+                    hood: self.hood,
+                };
+                (self.rule)(&context)
+                    .filter(|cell| cell != context.cell())
+                    .map(|cell| (point, cell))
+            })
+            .collect();
+        updates
+            .into_iter()
+            .map(|(point, cell)| {
+                grid[point] = cell;
+                point
+            })
+            .collect()
+    }
+
+    fn step_asynchronous<T>(&mut self, grid: &mut Grid<T>, frontier: &[Point]) -> Vec<Point>
+    where
+        T: PartialEq,
+        R: FnMut(&Context<T>) -> Option<T>,
+    {
+        let mut changed = Vec::new();
+        for &point in frontier {
+            let context = Context {
+                grid,
+                point,
+                hood: self.hood,
+            };
+            if let Some(cell) = (self.rule)(&context).filter(|cell| cell != context.cell()) {
+                grid[point] = cell;
+                changed.push(point);
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Stop, Update};
+    use crate::advent_of_code::{Grid, Neighbourhood, grid_of};
+
+    #[test]
+    fn removal_reaches_a_fixpoint() {
+        // the example of day 4
+        let grid = grid_of(&[
+            "..@@.@@@@.",
+            "@@@.@.@.@@",
+            "@@@@@.@.@@",
+            "@.@@@@..@.",
+            "@@.@@@@.@@",
+            ".@@@@@@@.@",
+            ".@.@.@.@@@",
+            "@.@@@.@@@@",
+            ".@@@@@@@@.",
+            "@.@.@@@.@.",
+        ]);
+        let remove = |cell: &super::Context<char>| {
+            (*cell.cell() == '@' && cell.count(|&c| c == '@') < 4).then_some('.')
+        };
+        let run = Automaton::new(Neighbourhood::Moore, remove).run(grid.clone());
+        assert_eq!(run.stop, Stop::Fixpoint);
+        assert_eq!(run.changes[0], 13);
+        assert_eq!(run.total_changes(), 43);
+
+        // removing in place frees rolls earlier, but ends in the same state
+        let run_in_place = Automaton::new(Neighbourhood::Moore, remove)
+            .update(Update::Asynchronous)
+            .run(grid);
+        assert_eq!(run_in_place.total_changes(), 43);
+        assert!(run_in_place.generations() < run.generations());
+        assert_eq!(run_in_place.grid, run.grid);
+    }
+
+    #[test]
+    fn blinker_cycles() {
+        // Conway's game of life
+        let life = |cell: &super::Context<char>| {
+            let alive = cell.count(|&c| c == '#');
+            match (*cell.cell(), alive) {
+                ('#', 2 | 3) | ('.', 3) => Some('#'),
+                _ => Some('.'),
+            }
+        };
+        let grid = grid_of(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let run = Automaton::new(Neighbourhood::Moore, life).run_detecting_cycles(grid.clone());
+        assert_eq!(
+            run.stop,
+            Stop::Cycle {
+                first: 0,
+                period: 2
+            }
+        );
+        assert_eq!(run.changes, [4, 4]);
+        assert_eq!(run.grid, grid);
+
+        let run = Automaton::new(Neighbourhood::Moore, life)
+            .limit(3)
+            .run(grid);
+        assert_eq!(run.stop, Stop::Limit);
+        assert_eq!(
+            run.grid,
+            grid_of(&[".....", ".....", ".###.", ".....", "....."])
+        );
+    }
+
+    #[test]
+    fn cells_without_hash() {
+        // f64 is neither Eq nor Hash, runs without cycle detection only need PartialEq
+        let grid = Grid::from_vec(1, 4, vec![1.0, 0.0, 0.0, 0.5]);
+        let spread = |cell: &super::Context<f64>| {
+            (*cell.cell() < 1.0 && cell.count(|&c| c >= 1.0) > 0).then_some(1.0)
+        };
+        let run = Automaton::new(Neighbourhood::Orthogonal, spread).run(grid);
+        assert_eq!(run.stop, Stop::Fixpoint);
+        assert_eq!(run.changes, [1, 1, 1]);
+        assert_eq!(run.grid.cells(), [1.0; 4]);
+    }
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use super::{Answer, InputError, Puzzle};
+
+/// Minimum, median and maximum of repeated measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Statistics of `durations`, which must not be empty.
+    pub fn new(durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+}
+
+/// Timing of one part, solved `runs` times on the same input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Measurement {
+    pub day: u32,
+    pub part: u32,
+    pub runs: usize,
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Solves `part` on `data` `runs` times, `None` if the part is unsolved.
+pub fn measure(
+    puzzle: &dyn Puzzle,
+    part: u32,
+    data: &[String],
+    runs: usize,
+) -> Result<Option<Measurement>, InputError> {
+    let mut answer = Answer::Unsolved;
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let (run_answer, timing) = puzzle.solve_timed(part, data)?;
+        if run_answer == Answer::Unsolved {
+            return Ok(None);
+        }
+        answer = run_answer;
+        parse.push(timing.parse);
+        solve.push(timing.solve);
+    }
+    Ok(Some(Measurement {
+        day: puzzle.day(),
+        part,
+        runs: parse.len(),
+        answer: answer.to_string(),
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    }))
+}
+
+/// All measurements of a bench run, written as JSON to track regressions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report is valid JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Report, Stats, measure};
+    use crate::advent_of_code::{Sum, lines};
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats_of_durations() {
+        let stats = Stats::new(&ms(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::new(&ms(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(Stats::new(&ms(&[7])).median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn measure_parts() {
+        let data = lines(&["1", "2"]);
+        let measurement = measure(&Sum, 1, &data, 5).unwrap().unwrap();
+        assert_eq!((measurement.day, measurement.part), (42, 1));
+        assert_eq!(measurement.runs, 5);
+        assert_eq!(measurement.answer, "3");
+        assert!(measurement.solve.min <= measurement.solve.max);
+
+        assert!(measure(&Sum, 2, &data, 5).unwrap().is_none());
+        assert!(measure(&Sum, 1, &["x".to_string()], 5).is_err());
+    }
+
+    #[test]
+    fn report_as_json() {
+        let data = vec!["1".to_string()];
+        let report = Report {
+            measurements: vec![measure(&Sum, 1, &data, 1).unwrap().unwrap()],
+        };
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        let measurement = &json["measurements"][0];
+        assert_eq!(measurement["day"], 42);
+        assert_eq!(measurement["answer"], "1");
+        assert!(measurement["solve"]["median_ns"].is_u64());
+    }
+}
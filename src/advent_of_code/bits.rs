@@ -0,0 +1,348 @@
+use super::{Grid, Neighbourhood, Point};
+
+const WORD: usize = u64::BITS as usize;
+
+/// Grid of booleans packed 64 cells to a word, for simulations over whole grids at once.
+///
+/// Each row starts at a new word, bit `j` of word `i` in a row is column `64 * i + j`.
+/// Bits past the last column are always clear.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl BitGrid {
+    /// A grid with every cell clear.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let words_per_row = cols.div_ceil(WORD);
+        BitGrid {
+            words: vec![0; rows * words_per_row],
+            words_per_row,
+            rows,
+            cols,
+        }
+    }
+
+    /// The cells of a dense grid for which `set` holds.
+    pub fn from_grid<T, F>(grid: &Grid<T>, set: F) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut bits = BitGrid::new(grid.rows(), grid.cols());
+        for (p, cell) in grid.iter() {
+            if set(cell) {
+                bits.set(p, true);
+            }
+        }
+        bits
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let cells = (0..self.rows)
+            .flat_map(|row| (0..self.cols).map(move |col| self.get(Point::new(row, col))))
+            .collect();
+        Grid::from_vec(self.rows, self.cols, cells)
+    }
+
+    fn word_index(&self, p: Point) -> (usize, u64) {
+        assert!(
+            p.row < self.rows && p.col < self.cols,
+            "{:?} outside of the {}x{} grid",
+            p,
+            self.rows,
+            self.cols
+        );
+        (
+            p.row * self.words_per_row + p.col / WORD,
+            1 << (p.col % WORD),
+        )
+    }
+
+    pub fn get(&self, p: Point) -> bool {
+        let (index, bit) = self.word_index(p);
+        self.words[index] & bit != 0
+    }
+
+    pub fn set(&mut self, p: Point, value: bool) {
+        let (index, bit) = self.word_index(p);
+        match value {
+            true => self.words[index] |= bit,
+            false => self.words[index] &= !bit,
+        }
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn none(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Positions of the set cells, in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let row = index / self.words_per_row;
+                let first_col = (index % self.words_per_row) * WORD;
+                BitsOf(word).map(move |bit| Point::new(row, first_col + bit))
+            })
+    }
+
+    fn zip_with(&self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "grids of different sizes"
+        );
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            ..*self
+        }
+    }
+
+    pub fn and(&self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    pub fn or(&self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Cells set here but not in `other`.
+    pub fn and_not(&self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// Cells that differ between the grids, like the changes between two generations.
+    pub fn diff(&self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Every cell flipped.
+    pub fn not(&self) -> BitGrid {
+        let mut inverted = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..*self
+        };
+        inverted.clear_padding();
+        inverted
+    }
+
+    fn clear_padding(&mut self) {
+        let used = self.cols % WORD;
+        if used != 0 {
+            let mask = (1u64 << used) - 1;
+            for row in self.words.chunks_exact_mut(self.words_per_row) {
+                row[self.words_per_row - 1] &= mask;
+            }
+        }
+    }
+
+    /// Number of set cells among the `Moore` neighbours of `p`.
+    pub fn neighbour_count(&self, p: Point) -> usize {
+        let mut count = 0;
+        for (dr, dc) in Neighbourhood::Moore.deltas() {
+            let (row, col) = (p.row as i64 + dr, p.col as i64 + dc);
+            if (0..self.rows as i64).contains(&row)
+                && (0..self.cols as i64).contains(&col)
+                && self.get(Point::new(row as usize, col as usize))
+            {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Cells with fewer than `k` set `Moore` neighbours, set or not themselves.
+    pub fn neighbours_fewer_than(&self, k: u8) -> BitGrid {
+        let mut result = BitGrid::new(self.rows, self.cols);
+        for (index, planes) in self.count_planes().into_iter().enumerate() {
+            result.words[index] = less_than(&planes, k);
+        }
+        result.clear_padding();
+        result
+    }
+
+    /// Cells with at least `k` set `Moore` neighbours, set or not themselves.
+    pub fn neighbours_at_least(&self, k: u8) -> BitGrid {
+        self.neighbours_fewer_than(k).not()
+    }
+
+    // The neighbour count of every cell as 4 bit planes per word: bit `j` of plane `i` is bit `i`
+    // of the count of the cell at bit `j`. The 8 neighbour masks are summed with a ripple carry.
+    fn count_planes(&self) -> Vec<[u64; 4]> {
+        let n = self.words_per_row;
+        let zero = vec![0; n];
+        let row = |r: Option<usize>| match r {
+            Some(r) if r < self.rows => &self.words[r * n..(r + 1) * n],
+            _ => &zero[..],
+        };
+        let mut planes = Vec::with_capacity(self.words.len());
+        for r in 0..self.rows {
+            let (up, mid, down) = (row(r.checked_sub(1)), row(Some(r)), row(Some(r + 1)));
+            for i in 0..n {
+                let mut sum = [0u64; 4];
+                for line in [up, down] {
+                    add(&mut sum, line[i]);
+                    add(&mut sum, from_left(line, i));
+                    add(&mut sum, from_right(line, i));
+                }
+                add(&mut sum, from_left(mid, i));
+                add(&mut sum, from_right(mid, i));
+                planes.push(sum);
+            }
+        }
+        planes
+    }
+}
+
+// word `i` of the row shifted so every bit holds its left neighbour
+fn from_left(row: &[u64], i: usize) -> u64 {
+    let carry = if i > 0 { row[i - 1] >> (WORD - 1) } else { 0 };
+    (row[i] << 1) | carry
+}
+
+// word `i` of the row shifted so every bit holds its right neighbour
+fn from_right(row: &[u64], i: usize) -> u64 {
+    let carry = row.get(i + 1).map_or(0, |w| w << (WORD - 1));
+    (row[i] >> 1) | carry
+}
+
+// adds 1 to the count of every bit set in `mask`
+fn add(planes: &mut [u64; 4], mask: u64) {
+    let mut carry = mask;
+    for plane in planes.iter_mut() {
+        let sum = *plane ^ carry;
+        carry &= *plane;
+        *plane = sum;
+    }
+}
+
+// bits whose count is below `k`, comparing from the highest plane down
+fn less_than(planes: &[u64; 4], k: u8) -> u64 {
+    if k > 15 {
+        return u64::MAX;
+    }
+    let mut less = 0;
+    let mut equal = u64::MAX;
+    for (i, &plane) in planes.iter().enumerate().rev() {
+        if k >> i & 1 == 1 {
+            less |= equal & !plane;
+            equal &= plane;
+        } else {
+            equal &= !plane;
+        }
+    }
+    less
+}
+
+// indices of the set bits of a word
+struct BitsOf(u64);
+
+impl Iterator for BitsOf {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitGrid;
+    use crate::advent_of_code::{Point, grid_of};
+
+    // a pattern that crosses word boundaries and hits both edges
+    fn pattern(rows: usize, cols: usize) -> BitGrid {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut bits = BitGrid::new(rows, cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                bits.set(Point::new(row, col), state.is_multiple_of(3));
+            }
+        }
+        bits
+    }
+
+    #[test]
+    fn get_set_and_iterate() {
+        let mut bits = BitGrid::new(2, 70);
+        bits.set(Point::new(0, 0), true);
+        bits.set(Point::new(1, 64), true);
+        bits.set(Point::new(1, 69), true);
+        assert!(bits.get(Point::new(1, 64)));
+        assert!(!bits.get(Point::new(1, 63)));
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(
+            bits.iter_ones().collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(1, 64), Point::new(1, 69)]
+        );
+        bits.set(Point::new(1, 64), false);
+        assert_eq!(bits.count_ones(), 2);
+        assert_eq!(bits.not().count_ones(), 2 * 70 - 2);
+    }
+
+    #[test]
+    fn word_parallel_counts_match_cell_by_cell() {
+        let bits = pattern(6, 130);
+        for k in 0..=9 {
+            let fewer = bits.neighbours_fewer_than(k);
+            for row in 0..bits.rows() {
+                for col in 0..bits.cols() {
+                    let p = Point::new(row, col);
+                    assert_eq!(
+                        fewer.get(p),
+                        bits.neighbour_count(p) < k as usize,
+                        "{:?} with k = {}",
+                        p,
+                        k
+                    );
+                }
+            }
+            assert_eq!(
+                fewer.count_ones() + bits.neighbours_at_least(k).count_ones(),
+                6 * 130
+            );
+        }
+    }
+
+    #[test]
+    fn diff_between_generations() {
+        let grid = grid_of(&["@@.", ".@@"]);
+        let before = BitGrid::from_grid(&grid, |&c| c == '@');
+        let after = before.and_not(&before.neighbours_fewer_than(3));
+        let changed: Vec<Point> = before.diff(&after).iter_ones().collect();
+        assert_eq!(changed, [Point::new(0, 0), Point::new(1, 2)]);
+        assert_eq!(after.or(&before), before);
+        assert_eq!(after.and(&before), after);
+        assert!(after.to_grid()[(0, 1)]);
+    }
+}
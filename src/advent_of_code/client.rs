@@ -0,0 +1,452 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{Answer, Rejection};
+use crate::info;
+
+/// Downloads puzzle inputs from and submits answers to adventofcode.com,
+/// or to whatever server `base_url` points to.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    // when the next answer may be submitted, as seconds since the Unix epoch
+    submit_file: Option<PathBuf>,
+}
+
+/// The server's reply to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Rejected(Rejection),
+    /// Answers were submitted too recently, `wait` is how long until the next one is accepted.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part is solved already, so the answer was not checked.
+    AlreadySolved,
+    /// A reply that is not understood, as plain text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Interprets the HTML page returned for a submitted answer.
+    pub fn parse(html: &str) -> Verdict {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            Verdict::Rejected(if text.contains("your answer is too high") {
+                Rejection::TooHigh
+            } else if text.contains("your answer is too low") {
+                Rejection::TooLow
+            } else {
+                Rejection::Wrong
+            })
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Rejected(rejection) => write!(f, "{}", rejection),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unknown reply: {}", text),
+        }
+    }
+}
+
+// the text of the <article> holding the reply, or of the whole page if there is none
+fn article_text(html: &str) -> String {
+    // the article starts inside its opening tag
+    let (article, mut in_tag) = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((html, false), |(article, _)| (article, true));
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// "You have 1m 38s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for token in wait.split_whitespace() {
+        let unit = match token.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += token[..token.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Everything that can go wrong while talking to the puzzle server.
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor the session file hold a token.
+    NoSession {
+        file: Option<PathBuf>,
+    },
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+    },
+    /// The server could not be reached or the response could not be read.
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession { file } => {
+                write!(f, "no session token, set {}", Client::SESSION_VAR)?;
+                match file {
+                    Some(file) => write!(f, " or write it to {}", file.display()),
+                    None => Ok(()),
+                }
+            }
+            ClientError::Status { url, status } => write!(f, "{}: HTTP status {}", url, status),
+            ClientError::Transport { url, message } => write!(f, "{}: {}", url, message),
+            ClientError::Io { path, source } => write!(f, "writing {}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Client {
+    pub const BASE_URL: &str = "https://adventofcode.com/2025";
+    /// Environment variable overriding `BASE_URL`, e.g. to point at a local stand-in server.
+    pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+    /// Environment variable holding the session token.
+    pub const SESSION_VAR: &str = "AOC_SESSION";
+    /// File in the home directory holding the session token, if `AOC_SESSION` is not set.
+    pub const SESSION_FILE: &str = ".adventofcode.session";
+    /// File in the home directory holding when the next answer may be submitted.
+    pub const SUBMIT_FILE: &str = ".adventofcode.next_submit";
+    /// Time between two submitted answers, unless the server asks to wait longer.
+    pub const SUBMIT_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc_2025/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            submit_file: None,
+        }
+    }
+
+    /// Keeps the time of the next allowed submission in `path`, so that it holds across runs.
+    pub fn with_submit_file(mut self, path: PathBuf) -> Self {
+        self.submit_file = Some(path);
+        self
+    }
+
+    /// Client configured by the environment, see `BASE_URL_VAR`, `SESSION_VAR`, `SESSION_FILE`
+    /// and `SUBMIT_FILE`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let client = Client::configure(
+            std::env::var(Client::BASE_URL_VAR).ok(),
+            std::env::var(Client::SESSION_VAR).ok(),
+            home.as_ref().map(|home| home.join(Client::SESSION_FILE)),
+        )?;
+        Ok(match home {
+            Some(home) => client.with_submit_file(home.join(Client::SUBMIT_FILE)),
+            None => client,
+        })
+    }
+
+    fn configure(
+        base_url: Option<String>,
+        session: Option<String>,
+        session_file: Option<PathBuf>,
+    ) -> Result<Self, ClientError> {
+        let base_url = base_url
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| Client::BASE_URL.to_string());
+        let session = session
+            .filter(|session| !session.trim().is_empty())
+            .or_else(|| {
+                let file = session_file.as_ref()?;
+                std::fs::read_to_string(file).ok()
+            })
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::NoSession { file: session_file })?;
+        Ok(Client::new(&base_url, &session))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Downloads the input of `day`.
+    pub fn input(&self, day: u32) -> Result<String, ClientError> {
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        Client::read_response(url, response)
+    }
+
+    /// Submits `answer` of `part` of `day`.
+    ///
+    /// Records when the next answer may be submitted, see `submit_wait`. The answer is sent even
+    /// if that is not yet, waiting is up to the caller.
+    pub fn submit(&self, day: u32, part: u32, answer: &Answer) -> Result<Verdict, ClientError> {
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", part.to_string().as_str()),
+                ("answer", answer.to_string().as_str()),
+            ]);
+        let verdict = Client::read_response(url, response).map(|html| Verdict::parse(&html))?;
+        let wait = match verdict {
+            Verdict::RateLimited { wait: Some(wait) } => wait,
+            _ => Client::SUBMIT_INTERVAL,
+        };
+        self.record_next_submit(wait);
+        Ok(verdict)
+    }
+
+    /// How long until the next answer may be submitted, zero without a submit file.
+    pub fn submit_wait(&self) -> Duration {
+        let next = self
+            .submit_file
+            .as_ref()
+            .and_then(|file| std::fs::read_to_string(file).ok())
+            .and_then(|secs| secs.trim().parse::<u64>().ok());
+        match next {
+            Some(secs) => (UNIX_EPOCH + Duration::from_secs(secs))
+                .duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+            None => Duration::ZERO,
+        }
+    }
+
+    // the verdict matters more than the record, the server enforces its limit anyway
+    fn record_next_submit(&self, wait: Duration) {
+        let Some(file) = &self.submit_file else {
+            return;
+        };
+        let next = SystemTime::now() + wait;
+        // rounded up to whole seconds
+        let secs = next
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() + 1);
+        if let Err(e) = std::fs::write(file, secs.to_string()) {
+            info!(
+                "cannot record the next submission in {}: {}",
+                file.display(),
+                e
+            );
+        }
+    }
+
+    fn read_response(
+        url: String,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        let response = response.map_err(|e| match e {
+            ureq::Error::Status(status, _) => ClientError::Status {
+                url: url.clone(),
+                status,
+            },
+            // the transport error itself names the url again
+            ureq::Error::Transport(transport) => ClientError::Transport {
+                url: url.clone(),
+                message: [
+                    Some(transport.kind().to_string()),
+                    transport.message().map(String::from),
+                    std::error::Error::source(&transport).map(|e| e.to_string()),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<String>>()
+                .join(": "),
+            },
+        })?;
+        response.into_string().map_err(|e| ClientError::Transport {
+            url,
+            message: e.to_string(),
+        })
+    }
+
+    /// Downloads the input of `day` to `path`, unless `path` already exists.
+    ///
+    /// Returns whether the input was downloaded. Inputs never change, so a cached input is never fetched again.
+    pub fn fetch_input(&self, day: u32, path: &Path) -> Result<bool, ClientError> {
+        if path.exists() {
+            return Ok(false);
+        }
+        let input = self.input(day)?;
+
+        // write to a temporary file first, so an interrupted download is not mistaken for the input
+        let io_error = |source| ClientError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, input).map_err(io_error)?;
+        std::fs::rename(&partial, path).map_err(io_error)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Client, ClientError, Verdict};
+    use crate::advent_of_code::Rejection;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        let correct = page("That's the right answer!  You are <em>one gold star</em> closer.");
+        assert_eq!(Verdict::parse(&correct), Verdict::Correct);
+
+        let too_high = page(
+            "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+        );
+        assert_eq!(
+            Verdict::parse(&too_high),
+            Verdict::Rejected(Rejection::TooHigh)
+        );
+        let too_low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(
+            Verdict::parse(&too_low),
+            Verdict::Rejected(Rejection::TooLow)
+        );
+        let wrong = page("That's not the right answer.  If you're stuck, ...");
+        assert_eq!(Verdict::parse(&wrong), Verdict::Rejected(Rejection::Wrong));
+
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Verdict::parse(&solved), Verdict::AlreadySolved);
+
+        assert_eq!(
+            Verdict::parse("<p>Something <b>else</b></p>"),
+            Verdict::Unknown("Something else".to_string())
+        );
+        assert_eq!(
+            Verdict::parse("Bad  request\n"),
+            Verdict::Unknown("Bad request".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_rate_limit() {
+        let limited = |wait: &str| {
+            Verdict::parse(&page(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a>",
+                wait
+            )))
+        };
+        assert_eq!(
+            limited("1m 38s"),
+            Verdict::RateLimited {
+                wait: Some(Duration::from_secs(98))
+            }
+        );
+        assert_eq!(
+            limited("12s"),
+            Verdict::RateLimited {
+                wait: Some(Duration::from_secs(12))
+            }
+        );
+        assert_eq!(limited("a while"), Verdict::RateLimited { wait: None });
+    }
+
+    #[test]
+    fn configure_defaults() {
+        let client = Client::configure(None, Some("abc\n".to_string()), None).unwrap();
+        assert_eq!(client.base_url(), Client::BASE_URL);
+        assert_eq!(client.session, "abc");
+
+        let client = Client::configure(
+            Some("http://127.0.0.1:8080/".to_string()),
+            Some("abc".to_string()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(client.base_url(), "http://127.0.0.1:8080");
+    }
+
+    #[test]
+    fn configure_reads_session_file() {
+        let file = std::env::temp_dir().join(format!("aoc_2025_session_{}", std::process::id()));
+        std::fs::write(&file, "from-file\n").unwrap();
+        let from_file = Client::configure(None, None, Some(file.clone())).map(|c| c.session);
+        let from_var = Client::configure(None, Some("from-var".to_string()), Some(file.clone()))
+            .map(|c| c.session);
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(from_file.unwrap(), "from-file");
+        assert_eq!(from_var.unwrap(), "from-var");
+
+        let err = Client::configure(None, Some(" ".to_string()), Some(file)).err();
+        assert!(matches!(
+            err,
+            Some(ClientError::NoSession { file: Some(_) })
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Everything that can go wrong while loading or parsing a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing { path: PathBuf },
+    /// Any other failure while opening or reading the input file.
+    Io { path: PathBuf, source: io::Error },
+    /// The line (1-based) is not valid UTF-8.
+    NotUtf8 { path: PathBuf, line: usize },
+    /// The input could not be parsed at line and column (both 1-based), columns count characters.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl InputError {
+    /// Creates a parse error from a 0-based line index and a 0-based column index in characters.
+    pub fn parse(line_index: usize, column_index: usize, message: impl Into<String>) -> Self {
+        InputError::Parse {
+            line: line_index + 1,
+            column: column_index + 1,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn io(path: PathBuf, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::NotFound => InputError::Missing { path },
+            _ => InputError::Io { path, source },
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(f, "input file {} not found", path.display()),
+            InputError::Io { path, source } => write!(f, "reading {}: {}", path.display(), source),
+            InputError::NotUtf8 { path, line } => {
+                write!(f, "{}:{}: line is not valid UTF-8", path.display(), line)
+            }
+            InputError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Column (0-based, in characters) of `field` within `line`.
+///
+/// `field` is meant to be a slice of `line`. Any other string is looked up in `line`, and if it is
+/// not found the column is the end of the line.
+pub fn column_of(line: &str, field: &str) -> usize {
+    let bytes = line.as_bytes().as_ptr_range();
+    let start = field.as_ptr();
+    let offset = if bytes.start <= start && start <= bytes.end {
+        start as usize - bytes.start as usize
+    } else {
+        line.find(field).unwrap_or(line.len())
+    };
+    line[..offset].chars().count()
+}
+
+/// Parses `field`, a slice of the line with index `line_index`, reporting where it failed.
+pub fn parse_field<T>(line: &str, field: &str, line_index: usize) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field.parse::<T>().map_err(|e| {
+        InputError::parse(
+            line_index,
+            column_of(line, field),
+            format!("cannot parse '{}': {}", field, e),
+        )
+    })
+}
+
+/// Checks that every line only consists of the `allowed` characters.
+pub fn expect_chars(data: &[String], allowed: &str) -> Result<(), InputError> {
+    for (line_index, line) in data.iter().enumerate() {
+        if let Some((column, c)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !allowed.contains(*c))
+        {
+            return Err(InputError::parse(
+                line_index,
+                column,
+                format!(
+                    "unexpected character '{}', expected one of \"{}\"",
+                    c, allowed
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputError, column_of, expect_chars, parse_field};
+
+    #[test]
+    fn parse_field_reports_position() {
+        let line = "12,x4,7";
+        let fields: Vec<&str> = line.split(',').collect();
+        assert_eq!(column_of(line, fields[2]), 6);
+        assert_eq!(parse_field::<u32>(line, fields[0], 0).unwrap(), 12);
+
+        let err = parse_field::<u32>(line, fields[1], 4).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Parse {
+                line: 5,
+                column: 4,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 4: cannot parse 'x4': invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn column_of_counts_characters() {
+        let line = "äö,x4";
+        let (_, field) = line.split_once(',').unwrap();
+        assert_eq!(column_of(line, field), 3);
+        // a copy of the field is looked up
+        let copy = field.to_owned();
+        assert_eq!(column_of(line, &copy), 3);
+        assert_eq!(column_of(line, "y"), 5);
+        assert_eq!(column_of(line, &line[line.len()..]), 5);
+    }
+
+    #[test]
+    fn expect_chars_finds_first_unexpected() {
+        let data = vec!["..@".to_string(), ".#.".to_string()];
+        assert!(expect_chars(&data, ".@#").is_ok());
+        let err = expect_chars(&data, ".@").unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+    }
+}
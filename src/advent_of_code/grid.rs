@@ -0,0 +1,435 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::{InputError, Neighbourhood};
+
+/// Position of a cell in a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(p: Point) -> Self {
+        (p.row, p.col)
+    }
+}
+
+/// What to do with lines that are shorter or longer than the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged<T> {
+    /// Fail on the first line with a different length than the first one.
+    Reject,
+    /// Pad shorter lines up to the longest one with the given cell.
+    Pad(T),
+}
+
+/// A rectangular grid of cells, stored row by row in one buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = char> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `rows` x `cols` from the cells in row-major order.
+    ///
+    /// Panics if the number of cells does not match.
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            rows * cols,
+            "a {}x{} grid needs {} cells",
+            rows,
+            cols,
+            rows * cols
+        );
+        Grid { cells, rows, cols }
+    }
+
+    pub fn filled(rows: usize, cols: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_vec(rows, cols, vec![cell; rows * cols])
+    }
+
+    /// Grid from rows of cells, `ragged` decides what happens to rows of different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>, ragged: Ragged<T>) -> Result<Self, InputError>
+    where
+        T: Clone,
+    {
+        let cols = match ragged {
+            Ragged::Reject => rows.first().map_or(0, Vec::len),
+            Ragged::Pad(_) => rows.iter().map(Vec::len).max().unwrap_or(0),
+        };
+        let row_count = rows.len();
+        let mut cells = Vec::with_capacity(row_count * cols);
+        for (index, mut row) in rows.into_iter().enumerate() {
+            match &ragged {
+                Ragged::Pad(fill) => row.resize(cols, fill.clone()),
+                Ragged::Reject if row.len() != cols => {
+                    return Err(InputError::parse(
+                        index,
+                        row.len().min(cols),
+                        format!("line has {} cells, expected {}", row.len(), cols),
+                    ));
+                }
+                Ragged::Reject => {}
+            }
+            cells.extend(row);
+        }
+        Ok(Grid::from_vec(row_count, cols, cells))
+    }
+
+    /// Maps every char of the lines to a cell, `None` marks a char without a cell.
+    ///
+    /// Fails with the line and column of the first char that could not be mapped, or of a ragged line.
+    pub fn from_lines_with<F>(lines: &[String], mut map: F) -> Result<Self, InputError>
+    where
+        F: FnMut(char) -> Option<T>,
+        T: Clone,
+    {
+        Grid::try_from_lines(lines, |c| {
+            map(c).ok_or_else(|| "no cell for it".to_string())
+        })
+    }
+
+    /// Parses every char of the lines with `T: TryFrom<char>`.
+    ///
+    /// Fails with the line and column of the first char that could not be converted, or of a ragged line.
+    pub fn parse(lines: &[String]) -> Result<Self, InputError>
+    where
+        T: TryFrom<char> + Clone,
+        T::Error: fmt::Display,
+    {
+        Grid::try_from_lines(lines, |c| T::try_from(c).map_err(|e| e.to_string()))
+    }
+
+    fn try_from_lines<F>(lines: &[String], mut map: F) -> Result<Self, InputError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+        T: Clone,
+    {
+        let mut rows = Vec::with_capacity(lines.len());
+        for (row, line) in lines.iter().enumerate() {
+            let cells = line
+                .chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    map(c).map_err(|message| {
+                        InputError::parse(
+                            row,
+                            col,
+                            format!("unexpected character '{}': {}", c, message),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<T>, InputError>>()?;
+            rows.push(cells);
+        }
+        Grid::from_rows(rows, Ragged::Reject)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// All cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.row < self.rows && p.col < self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(|i| &mut self.cells[i])
+    }
+
+    /// Like `get`, for positions computed with offsets that may leave the grid on any side.
+    pub fn get_signed(&self, row: i64, col: i64) -> Option<&T> {
+        let row = usize::try_from(row).ok()?;
+        let col = usize::try_from(col).ok()?;
+        self.get(row, col)
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.rows && col < self.cols).then_some(row * self.cols + col)
+    }
+
+    /// The cells of one row.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of one column, from top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of {}", col, self.cols);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// The rows from top to bottom, `rows()` of them even if they are empty.
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn iter_columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// All cells with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i / cols, i % cols), cell))
+    }
+
+    /// get surrounding positions of a given position
+    pub fn get_surrounding_positions(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        self.neighbours(Point::new(row, col), Neighbourhood::Moore)
+            .map(Point::into)
+            .collect()
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// find all positions of a given cell in the grid
+    pub fn find_char_positions(&self, target: T) -> HashSet<(usize, usize)> {
+        self.iter()
+            .filter(|(_, cell)| **cell == target)
+            .map(|(p, _)| p.into())
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// get surrounding cells of a given position
+    pub fn get_surrounding_chars(&self, row: usize, col: usize) -> Vec<T> {
+        self.get_surrounding_positions(row, col)
+            .into_iter()
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Grid of the chars of the lines, which all need the same length.
+    pub fn from_lines(lines: &[String]) -> Result<Self, InputError> {
+        Grid::from_lines_with(lines, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p.row, p.col)
+            .unwrap_or_else(|| panic!("{:?} outside of the {}x{} grid", p, self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(p.row, p.col)
+            .unwrap_or_else(|| panic!("{:?} outside of the {}x{} grid", p, rows, cols))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self[Point::new(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self[Point::new(row, col)]
+    }
+}
+
+impl<T: fmt::Display> Grid<T> {
+    pub fn println(&self) {
+        for row in self.iter_rows() {
+            let line_str: String = row.iter().map(|c| c.to_string()).collect();
+            println!("{}", line_str);
+        }
+    }
+}
+
+/// Grid of the rows, for tests on grids written inline.
+#[cfg(test)]
+pub(crate) fn grid_of(rows: &[&str]) -> Grid {
+    Grid::from_lines(&super::lines(rows)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Point, Ragged};
+    use crate::advent_of_code::InputError;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Cell {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = &'static str;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Cell::Empty),
+                '#' => Ok(Cell::Wall),
+                _ => Err("expected '.' or '#'"),
+            }
+        }
+    }
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_typed_cells() {
+        let grid = Grid::<Cell>::parse(&lines("#.\n.#")).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 2));
+        assert_eq!(grid[(1, 1)], Cell::Wall);
+        assert_eq!(grid.find_char_positions(Cell::Wall).len(), 2);
+        assert_eq!(
+            grid.get_surrounding_chars(0, 0),
+            vec![Cell::Empty, Cell::Empty, Cell::Wall]
+        );
+
+        let err = Grid::<Cell>::parse(&lines("#.\n.#\n#x")).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Parse {
+                line: 3,
+                column: 2,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 2: unexpected character 'x': expected '.' or '#'"
+        );
+    }
+
+    #[test]
+    fn from_lines_with_function() {
+        let grid = Grid::from_lines_with(&lines("12\n34"), |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid.cells(), [1, 2, 3, 4]);
+
+        let err = Grid::from_lines_with(&lines("12\n3a"), |c| c.to_digit(10)).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let err = Grid::from_lines(&lines("abc\nab\nabc")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: line has 2 cells, expected 3"
+        );
+        let err = Grid::from_lines(&lines("ab\nabc")).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+
+        let rows = lines("1 2\n34\n5")
+            .iter()
+            .map(|l| l.chars().collect())
+            .collect();
+        let grid = Grid::from_rows(rows, Ragged::Pad(' ')).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (3, 3));
+        assert_eq!(grid.row(1), ['3', '4', ' ']);
+        assert_eq!(grid.row(2), ['5', ' ', ' ']);
+    }
+
+    #[test]
+    fn accessors() {
+        let mut grid = Grid::from_lines(&lines("abc\ndef")).unwrap();
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 0), Some(&'d'));
+
+        *grid.get_mut(0, 0).unwrap() = 'A';
+        grid[Point::new(1, 1)] = 'E';
+        assert_eq!(grid[(0, 0)], 'A');
+        assert_eq!(grid[Point::new(1, 1)], 'E');
+        assert!(grid.contains(Point::new(1, 2)));
+        assert!(!grid.contains(Point::new(2, 2)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_lines(&lines("abc\ndef")).unwrap();
+        let rows: Vec<String> = grid.iter_rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let cols: Vec<String> = grid.iter_columns().map(|c| c.collect()).collect();
+        assert_eq!(cols, ["ad", "be", "cf"]);
+        assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &'e')));
+
+        let empty = Grid::<char>::from_lines(&[]).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+        let no_columns = Grid::<char>::from_lines(&lines("\n\n")).unwrap();
+        assert_eq!((no_columns.rows(), no_columns.cols()), (2, 0));
+        assert_eq!(no_columns.iter_rows().count(), 2);
+        assert!(no_columns.iter_rows().all(<[char]>::is_empty));
+        assert_eq!(empty.iter_rows().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 2x3 grid")]
+    fn index_outside_panics() {
+        let grid = Grid::filled(2, 3, 0u8);
+        let _ = grid[(2, 0)];
+    }
+}
@@ -0,0 +1,117 @@
+//! Leveled debug output of the solvers, written to stderr so stdout only holds the answers.
+//!
+//! Nothing is logged by default. The level is taken from `AOC_LOG` (off, info, debug or trace),
+//! unless the runner sets it from its `-v` flags.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// What the runner is doing.
+    Info,
+    /// Summaries of the solvers, like the size of the parsed input.
+    Debug,
+    /// Every step of the solvers.
+    Trace,
+}
+
+/// Environment variable holding the level name, used unless `set_level` is called.
+pub const LEVEL_VAR: &str = "AOC_LOG";
+
+const LEVELS: [Level; 4] = [Level::Off, Level::Info, Level::Debug, Level::Trace];
+
+// index into LEVELS, or UNSET until the level is read from the environment
+const UNSET: u8 = u8::MAX;
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "off" | "" => Some(Level::Off),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    /// The level of `count` times `-v`.
+    pub fn from_verbosity(count: usize) -> Level {
+        LEVELS[count.min(LEVELS.len() - 1)]
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => {
+            let level = std::env::var(LEVEL_VAR)
+                .ok()
+                .and_then(|name| Level::from_name(&name))
+                .unwrap_or(Level::Off);
+            set_level(level);
+            level
+        }
+        index => LEVELS[index as usize],
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Logs like `eprintln!` at `Level::Info`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::advent_of_code::log::enabled($crate::advent_of_code::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Logs like `eprintln!` at `Level::Debug`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::advent_of_code::log::enabled($crate::advent_of_code::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Logs like `eprintln!` at `Level::Trace`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::advent_of_code::log::enabled($crate::advent_of_code::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Level;
+
+    #[test]
+    fn level_names() {
+        assert_eq!(Level::from_name("debug"), Some(Level::Debug));
+        assert_eq!(Level::from_name(" TRACE "), Some(Level::Trace));
+        assert_eq!(Level::from_name(""), Some(Level::Off));
+        assert_eq!(Level::from_name("loud"), None);
+    }
+
+    #[test]
+    fn verbosity_levels() {
+        assert_eq!(Level::from_verbosity(0), Level::Off);
+        assert_eq!(Level::from_verbosity(1), Level::Info);
+        assert_eq!(Level::from_verbosity(3), Level::Trace);
+        assert_eq!(Level::from_verbosity(7), Level::Trace);
+        assert!(Level::Info < Level::Debug);
+    }
+}
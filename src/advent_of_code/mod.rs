@@ -1,126 +1,150 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
+use std::ffi::OsString;
 use std::fs::File;
 use std::io::BufRead;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+mod answers;
+mod automaton;
+pub mod bench;
+mod bits;
+mod client;
+mod error;
+mod grid;
+pub mod log;
+mod neighbours;
+mod path;
+mod regions;
+mod render;
+mod solution;
+mod sparse;
+mod transform;
+
+pub use answers::{Answers, Check, Rejection};
+pub use automaton::{Automaton, Context, Run, Stop, Update};
+pub use bits::BitGrid;
+pub use client::{Client, ClientError, Verdict};
+pub use error::{InputError, column_of, expect_chars, parse_field};
+#[cfg(test)]
+pub(crate) use grid::grid_of;
+pub use grid::{Grid, Point, Ragged};
+pub use neighbours::{Neighbourhood, Neighbours};
+pub use path::{Distances, Route};
+pub use regions::{Region, Regions};
+pub use render::{Image, Rgb};
+#[cfg(test)]
+pub(crate) use solution::Sum;
+pub use solution::{Answer, Puzzle, Solution, Timing};
+pub use sparse::{Coord, SparseGrid};
+pub use transform::Symmetry;
 
 pub mod aoc {}
-#[allow(dead_code)]
-pub struct Reader {}
-#[allow(dead_code)]
-impl Reader {
-    // Returns an Iterator to the Reader of the lines of the file.
-    pub fn read_lines<P>(filename: P) -> std::io::Result<std::io::Lines<std::io::BufReader<File>>>
-    where
-        P: AsRef<Path>,
-    {
-        let file = File::open(filename)?;
-        Ok(std::io::BufReader::new(file).lines())
+
+/// The input files of a day, some puzzles come with a second example for part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variant {
+    Real,
+    Example,
+    Example2,
+}
+
+impl Variant {
+    pub fn file_name(self, day: u32) -> String {
+        match self {
+            Variant::Real => format!("day{:02}.txt", day),
+            Variant::Example => format!("day{:02}_test.txt", day),
+            Variant::Example2 => format!("day{:02}_test2.txt", day),
+        }
     }
 
-    pub fn read_file(filename: &str) -> std::io::Result<Vec<String>> {
-        let org_data = match Reader::read_lines(filename) {
-            Ok(lines) => lines.collect::<Result<_, _>>().unwrap(),
-            Err(e) => panic!("Error opening file {}: {}", filename, e),
-        };
-        Ok(org_data)
+    pub fn is_example(self) -> bool {
+        self != Variant::Real
+    }
+
+    /// Name of the variant in answers.toml.
+    pub fn name(self) -> &'static str {
+        match self {
+            Variant::Real => "real",
+            Variant::Example => "example",
+            Variant::Example2 => "example2",
+        }
     }
 }
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+pub struct Reader {}
+impl Reader {
+    /// Environment variable naming a directory with the real puzzle inputs, for inputs kept outside the repo.
+    pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
 
-pub struct Grid {
-    pub data: Vec<Vec<char>>,
-    pub rows: usize,
-    pub cols: usize,
-}
+    /// Path to the input of `day`, independent of the working directory.
+    ///
+    /// Real inputs are taken from `AOC_INPUT_DIR` if it is set, everything else from input/ of this crate.
+    pub fn input_path(day: u32, variant: Variant) -> PathBuf {
+        Reader::resolve_input_path(day, variant, std::env::var_os(Reader::INPUT_DIR_VAR))
+    }
 
-#[allow(dead_code)]
-impl Grid {
-    pub fn new(data: Vec<Vec<char>>) -> Self {
-        let rows = data.len();
-        let cols = if rows > 0 { data[0].len() } else { 0 };
-        Grid { data, rows, cols }
-    }
-
-    pub fn from_lines(lines: &Vec<String>) -> Self {
-        let data: Vec<Vec<char>> = lines
-            .iter()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect();
-        Grid::new(data)
-    }
-
-    // find all positions of a given char in the grid
-    pub fn find_char_positions(&self, target: char) -> HashSet<(usize, usize)> {
-        let mut positions: HashSet<(usize, usize)> = HashSet::new();
-        for (row_idx, row) in self.data.iter().enumerate() {
-            for (col_idx, &c) in row.iter().enumerate() {
-                if c == target {
-                    positions.insert((row_idx, col_idx));
-                }
-            }
-        }
-        positions
-    }
-
-    // get surrounding positions of a given position
-    pub fn get_surrounding_positions(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
-        const DELTAS: [(i32, i32); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-
-        let mut surrounding = Vec::with_capacity(8);
-        let row_i32 = row as i32;
-        let col_i32 = col as i32;
-
-        for (dr, dc) in DELTAS {
-            let new_row = row_i32 + dr;
-            let new_col = col_i32 + dc;
-
-            if new_row >= 0
-                && new_col >= 0
-                && (new_row as usize) < self.rows
-                && (new_col as usize) < self.cols
-            {
-                surrounding.push((new_row as usize, new_col as usize));
-            }
-        }
+    fn resolve_input_path(day: u32, variant: Variant, input_dir: Option<OsString>) -> PathBuf {
+        let dir = match (variant, input_dir) {
+            (Variant::Real, Some(dir)) if !dir.is_empty() => PathBuf::from(dir),
+            _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+        };
+        dir.join(variant.file_name(day))
+    }
 
-        surrounding
+    /// Reads the input of `day`, see `input_path`.
+    pub fn read_input(day: u32, variant: Variant) -> Result<Vec<String>, InputError> {
+        Reader::read_file(Reader::input_path(day, variant))
     }
 
-    // get surrounding chars of a given position
-    pub fn get_surrounding_chars(&self, row: usize, col: usize) -> Vec<char> {
-        self.get_surrounding_positions(row, col)
-            .into_iter()
-            .map(|(r, c)| self.data[r][c])
-            .collect()
+    /// Returns an Iterator to the Reader of the lines of the file.
+    pub fn read_lines<P>(
+        filename: P,
+    ) -> Result<std::io::Lines<std::io::BufReader<File>>, InputError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = filename.as_ref();
+        let file = File::open(path).map_err(|e| InputError::io(path.to_path_buf(), e))?;
+        Ok(std::io::BufReader::new(file).lines())
     }
 
-    pub fn println(&self) {
-        for row in &self.data {
-            let line_str: String = row.iter().collect();
-            println!("{}", line_str);
-        }
+    /// Reads all lines of the file, without their line endings.
+    pub fn read_file<P>(filename: P) -> Result<Vec<String>, InputError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = filename.as_ref();
+        let file = File::open(path).map_err(|e| InputError::io(path.to_path_buf(), e))?;
+        // split on raw bytes, so a line that is not UTF-8 can be reported with its number
+        std::io::BufReader::new(file)
+            .split(b'\n')
+            .enumerate()
+            .map(|(index, line)| {
+                let mut line = line.map_err(|e| InputError::io(path.to_path_buf(), e))?;
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                String::from_utf8(line).map_err(|_| InputError::NotUtf8 {
+                    path: path.to_path_buf(),
+                    line: index + 1,
+                })
+            })
+            .collect()
     }
 }
 
+/// Lines as `Reader` returns them, for tests on inputs written inline.
+#[cfg(test)]
+pub(crate) fn lines(s: &[&str]) -> Vec<String> {
+    s.iter().map(|l| l.to_string()).collect()
+}
+
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct LimitedVecDeque<T> {
     deque: VecDeque<T>,
     capacity: usize,
 }
 
-#[allow(dead_code)]
 impl<T> LimitedVecDeque<T> {
     /// Creates a new `LimitedVecDeque` with the given capacity.
     pub fn new(capacity: usize) -> Self {
@@ -146,15 +170,17 @@ impl<T> LimitedVecDeque<T> {
     pub fn len(&self) -> usize {
         self.deque.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
 }
 
-#[allow(dead_code)]
 pub struct RingBuffer<T> {
     buffer: Vec<T>,
     current_index: usize,
 }
 
-#[allow(dead_code)]
 impl<T: Clone> RingBuffer<T> {
     pub fn new(items: Vec<T>) -> Self {
         RingBuffer {
@@ -162,38 +188,26 @@ impl<T: Clone> RingBuffer<T> {
             current_index: 0,
         }
     }
-
-    pub fn next(&mut self) -> T {
-        let item = self.buffer[self.current_index].clone();
-        self.current_index = (self.current_index + 1) % self.buffer.len();
-        item
-    }
 }
 
-#[allow(dead_code)]
-pub fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = matrix.len();
-    let cols = matrix[0].len();
-    let mut transposed_matrix: Vec<Vec<char>> = vec![vec!['.'; rows]; cols];
+/// Endlessly cycles through the items, only an empty buffer returns `None`.
+impl<T: Clone> Iterator for RingBuffer<T> {
+    type Item = T;
 
-    for i in 0..rows {
-        for j in 0..cols {
-            transposed_matrix[j][i] = matrix[i][j];
-        }
+    fn next(&mut self) -> Option<T> {
+        let item = self.buffer.get(self.current_index)?.clone();
+        self.current_index = (self.current_index + 1) % self.buffer.len();
+        Some(item)
     }
-
-    transposed_matrix
 }
 
-#[allow(dead_code)]
-pub fn lines_to_matrix(lines: &Vec<String>) -> Vec<Vec<char>> {
+pub fn lines_to_matrix(lines: &[String]) -> Vec<Vec<char>> {
     lines
         .iter()
         .map(|line| line.chars().collect::<Vec<char>>())
         .collect::<Vec<Vec<char>>>()
 }
 
-#[allow(dead_code)]
 pub fn find_char(grid: Vec<Vec<char>>, char: char) -> (usize, usize) {
     for (row_idx, row) in grid.iter().enumerate() {
         for (col_idx, &c) in row.iter().enumerate() {
@@ -204,3 +218,45 @@ pub fn find_char(grid: Vec<Vec<char>>, char: char) -> (usize, usize) {
     }
     panic!("The character '{}' was not found in the grid.", char)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::Path;
+
+    use super::{Reader, Variant};
+
+    #[test]
+    fn variant_file_names() {
+        assert_eq!(Variant::Real.file_name(3), "day03.txt");
+        assert_eq!(Variant::Example.file_name(3), "day03_test.txt");
+        assert_eq!(Variant::Example2.file_name(11), "day11_test2.txt");
+    }
+
+    #[test]
+    fn input_path_is_inside_the_crate() {
+        let path = Reader::resolve_input_path(4, Variant::Example, None);
+        assert!(path.is_absolute());
+        assert_eq!(
+            path,
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("input/day04_test.txt")
+        );
+    }
+
+    #[test]
+    fn input_dir_overrides_real_inputs_only() {
+        let dir = Some(OsString::from("/private/aoc"));
+        assert_eq!(
+            Reader::resolve_input_path(4, Variant::Real, dir.clone()),
+            Path::new("/private/aoc/day04.txt")
+        );
+        assert_eq!(
+            Reader::resolve_input_path(4, Variant::Example, dir),
+            Reader::resolve_input_path(4, Variant::Example, None)
+        );
+        assert_eq!(
+            Reader::resolve_input_path(4, Variant::Real, Some(OsString::new())),
+            Reader::resolve_input_path(4, Variant::Real, None)
+        );
+    }
+}
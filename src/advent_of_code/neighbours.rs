@@ -0,0 +1,229 @@
+use super::{Grid, Point};
+
+/// Which cells around a position count as its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// The 4 cells sharing an edge (von Neumann).
+    Orthogonal,
+    /// The 8 cells sharing an edge or a corner (Moore).
+    Moore,
+    /// The 4 cells sharing only a corner.
+    Diagonal,
+    /// All cells within a Chebyshev distance, `Chebyshev(1)` is `Moore`.
+    Chebyshev(usize),
+    /// All cells within a Manhattan distance, `Manhattan(1)` is `Orthogonal`.
+    Manhattan(usize),
+}
+
+impl Neighbourhood {
+    fn radius(self) -> i64 {
+        match self {
+            Neighbourhood::Orthogonal | Neighbourhood::Moore | Neighbourhood::Diagonal => 1,
+            Neighbourhood::Chebyshev(r) | Neighbourhood::Manhattan(r) => r as i64,
+        }
+    }
+
+    /// Whether the offset `(dr, dc)` from a cell reaches one of its neighbours.
+    pub fn contains(self, dr: i64, dc: i64) -> bool {
+        let (r, c) = (dr.abs(), dc.abs());
+        let within = r <= self.radius() && c <= self.radius();
+        within
+            && (r, c) != (0, 0)
+            && match self {
+                Neighbourhood::Orthogonal => r + c == 1,
+                Neighbourhood::Diagonal => r == c,
+                Neighbourhood::Moore | Neighbourhood::Chebyshev(_) => true,
+                Neighbourhood::Manhattan(radius) => r + c <= radius as i64,
+            }
+    }
+
+    /// The offsets of the neighbours, row by row.
+    pub fn deltas(self) -> impl Iterator<Item = (i64, i64)> {
+        let r = self.radius();
+        (-r..=r)
+            .flat_map(move |dr| (-r..=r).map(move |dc| (dr, dc)))
+            .filter(move |&(dr, dc)| self.contains(dr, dc))
+    }
+}
+
+/// Iterator over the neighbours of a cell, see `Grid::neighbours`.
+///
+/// It only copies the grid's size, so the grid can be changed while iterating.
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    center: (i64, i64),
+    rows: i64,
+    cols: i64,
+    hood: Neighbourhood,
+    wrap: bool,
+    // next offset to try, scanning the square of the radius row by row
+    dr: i64,
+    dc: i64,
+}
+
+impl Neighbours {
+    fn new(grid_size: (usize, usize), p: Point, hood: Neighbourhood, wrap: bool) -> Self {
+        let r = hood.radius();
+        Neighbours {
+            center: (p.row as i64, p.col as i64),
+            rows: grid_size.0 as i64,
+            cols: grid_size.1 as i64,
+            hood,
+            wrap,
+            dr: -r,
+            dc: -r,
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        let r = self.hood.radius();
+        while self.dr <= r {
+            let (dr, dc) = (self.dr, self.dc);
+            self.dc += 1;
+            if self.dc > r {
+                self.dc = -r;
+                self.dr += 1;
+            }
+            if !self.hood.contains(dr, dc) {
+                continue;
+            }
+            let (row, col) = (self.center.0 + dr, self.center.1 + dc);
+            if self.wrap {
+                return Some(Point::new(
+                    row.rem_euclid(self.rows) as usize,
+                    col.rem_euclid(self.cols) as usize,
+                ));
+            }
+            if (0..self.rows).contains(&row) && (0..self.cols).contains(&col) {
+                return Some(Point::new(row as usize, col as usize));
+            }
+        }
+        None
+    }
+}
+
+impl<T> Grid<T> {
+    /// Positions of the neighbours of `p` inside the grid.
+    pub fn neighbours(&self, p: Point, hood: Neighbourhood) -> Neighbours {
+        Neighbours::new((self.rows(), self.cols()), p, hood, false)
+    }
+
+    /// Positions of the neighbours of `p`, with the edges of the grid wrapping around (a torus).
+    ///
+    /// A cell is returned more than once if the neighbourhood reaches around the whole grid.
+    pub fn neighbours_wrapping(&self, p: Point, hood: Neighbourhood) -> Neighbours {
+        assert!(
+            self.rows() > 0 && self.cols() > 0,
+            "an empty grid has no neighbours"
+        );
+        Neighbours::new((self.rows(), self.cols()), p, hood, true)
+    }
+
+    /// The neighbours of `p` inside the grid, with their cells.
+    pub fn neighbour_cells(
+        &self,
+        p: Point,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(p, hood).map(move |n| (n, &self[n]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Neighbourhood;
+    use crate::advent_of_code::{Grid, Point};
+
+    fn grid() -> Grid<u8> {
+        Grid::from_vec(5, 5, (0..25).collect())
+    }
+
+    fn cells(grid: &Grid<u8>, p: Point, hood: Neighbourhood) -> Vec<u8> {
+        grid.neighbour_cells(p, hood).map(|(_, &c)| c).collect()
+    }
+
+    #[test]
+    fn fixed_neighbourhoods() {
+        let grid = grid();
+        let center = Point::new(2, 2);
+        assert_eq!(
+            cells(&grid, center, Neighbourhood::Orthogonal),
+            [7, 11, 13, 17]
+        );
+        assert_eq!(
+            cells(&grid, center, Neighbourhood::Diagonal),
+            [6, 8, 16, 18]
+        );
+        assert_eq!(
+            cells(&grid, center, Neighbourhood::Moore),
+            [6, 7, 8, 11, 13, 16, 17, 18]
+        );
+        assert_eq!(
+            cells(&grid, Point::new(0, 0), Neighbourhood::Moore),
+            [1, 5, 6]
+        );
+        assert_eq!(
+            cells(&grid, Point::new(4, 0), Neighbourhood::Diagonal),
+            [16]
+        );
+    }
+
+    #[test]
+    fn radius_neighbourhoods() {
+        let grid = grid();
+        let center = Point::new(2, 2);
+        assert_eq!(
+            grid.neighbours(center, Neighbourhood::Chebyshev(2)).count(),
+            24
+        );
+        assert_eq!(
+            grid.neighbours(center, Neighbourhood::Manhattan(2)).count(),
+            12
+        );
+        assert_eq!(
+            cells(&grid, center, Neighbourhood::Manhattan(1)),
+            cells(&grid, center, Neighbourhood::Orthogonal)
+        );
+        assert_eq!(
+            cells(&grid, Point::new(0, 0), Neighbourhood::Manhattan(2)),
+            [1, 2, 5, 6, 10]
+        );
+        assert_eq!(
+            grid.neighbours(center, Neighbourhood::Chebyshev(0)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn wrapping_neighbourhoods() {
+        let grid = grid();
+        let corner: Vec<Point> = grid
+            .neighbours_wrapping(Point::new(0, 0), Neighbourhood::Orthogonal)
+            .collect();
+        assert_eq!(
+            corner,
+            [
+                Point::new(4, 0),
+                Point::new(0, 4),
+                Point::new(0, 1),
+                Point::new(1, 0)
+            ]
+        );
+        assert_eq!(
+            grid.neighbours_wrapping(Point::new(4, 4), Neighbourhood::Chebyshev(2))
+                .count(),
+            24
+        );
+    }
+
+    #[test]
+    fn deltas() {
+        let deltas: Vec<_> = Neighbourhood::Diagonal.deltas().collect();
+        assert_eq!(deltas, [(-1, -1), (-1, 1), (1, -1), (1, 1)]);
+        assert_eq!(Neighbourhood::Manhattan(3).deltas().count(), 24);
+    }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Grid, Neighbourhood, Point};
+
+/// Shortest distances from a start cell to every reachable cell of a grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distances {
+    start: Point,
+    dist: Grid<Option<u64>>,
+    prev: Grid<Option<Point>>,
+}
+
+/// A shortest path with its total cost, from the start to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cost: u64,
+    pub path: Vec<Point>,
+}
+
+impl Distances {
+    fn new<T>(grid: &Grid<T>, start: Point) -> Self {
+        assert!(
+            grid.contains(start),
+            "start {:?} outside of the grid",
+            start
+        );
+        let mut dist = Grid::filled(grid.rows(), grid.cols(), None);
+        dist[start] = Some(0);
+        Distances {
+            start,
+            dist,
+            prev: Grid::filled(grid.rows(), grid.cols(), None),
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Distance to `p`, `None` if it cannot be reached.
+    pub fn get(&self, p: Point) -> Option<u64> {
+        self.dist.get(p.row, p.col).copied().flatten()
+    }
+
+    /// The distance map, `None` for unreachable cells.
+    pub fn map(&self) -> &Grid<Option<u64>> {
+        &self.dist
+    }
+
+    /// One of the shortest paths from the start to `p`, both included.
+    pub fn path_to(&self, p: Point) -> Option<Vec<Point>> {
+        self.get(p)?;
+        let mut path = vec![p];
+        let mut current = p;
+        while let Some(prev) = self.prev[current] {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn route_to(&self, p: Point) -> Option<Route> {
+        Some(Route {
+            cost: self.get(p)?,
+            path: self.path_to(p)?,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Unweighted shortest paths from `start`, only entering cells that are `passable`.
+    pub fn bfs<P>(&self, start: Point, hood: Neighbourhood, passable: P) -> Distances
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut distances = Distances::new(self, start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(p) = queue.pop_front() {
+            let next = distances.dist[p].map(|d| d + 1);
+            for n in self.neighbours(p, hood) {
+                if distances.dist[n].is_none() && passable(&self[n]) {
+                    distances.dist[n] = next;
+                    distances.prev[n] = Some(p);
+                    queue.push_back(n);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Weighted shortest paths from `start`, `cost` gives the cost of entering a cell or `None` for walls.
+    pub fn dijkstra_by_cell<C>(&self, start: Point, hood: Neighbourhood, cost: C) -> Distances
+    where
+        C: Fn(&T) -> Option<u64>,
+    {
+        self.dijkstra(start, hood, |_, to| cost(&self[to]))
+    }
+
+    /// Weighted shortest paths from `start`, `cost` gives the cost of a move or `None` if it is not allowed.
+    pub fn dijkstra<C>(&self, start: Point, hood: Neighbourhood, cost: C) -> Distances
+    where
+        C: FnMut(Point, Point) -> Option<u64>,
+    {
+        self.search(start, None, hood, |_| 0, cost)
+    }
+
+    /// Shortest path from `start` to `goal` with orthogonal moves, guided by the Manhattan distance.
+    ///
+    /// The heuristic assumes every move costs at least 1, cheaper moves can give a longer path.
+    pub fn a_star<C>(&self, start: Point, goal: Point, cost: C) -> Option<Route>
+    where
+        C: FnMut(Point, Point) -> Option<u64>,
+    {
+        let manhattan = |p: Point| (p.row.abs_diff(goal.row) + p.col.abs_diff(goal.col)) as u64;
+        self.search(
+            start,
+            Some(goal),
+            Neighbourhood::Orthogonal,
+            manhattan,
+            cost,
+        )
+        .route_to(goal)
+    }
+
+    // Dijkstra, or A* with a heuristic; stops once `goal` is settled
+    fn search<H, C>(
+        &self,
+        start: Point,
+        goal: Option<Point>,
+        hood: Neighbourhood,
+        heuristic: H,
+        mut cost: C,
+    ) -> Distances
+    where
+        H: Fn(Point) -> u64,
+        C: FnMut(Point, Point) -> Option<u64>,
+    {
+        let mut distances = Distances::new(self, start);
+        let mut done = Grid::filled(self.rows(), self.cols(), false);
+        let mut heap = BinaryHeap::from([Reverse((heuristic(start), start))]);
+        while let Some(Reverse((_, p))) = heap.pop() {
+            if std::mem::replace(&mut done[p], true) {
+                continue;
+            }
+            if Some(p) == goal {
+                break;
+            }
+            let d = distances.dist[p].unwrap_or_default();
+            for n in self.neighbours(p, hood) {
+                let Some(step) = cost(p, n) else {
+                    continue;
+                };
+                let next = d + step;
+                if !done[n] && distances.dist[n].is_none_or(|old| next < old) {
+                    distances.dist[n] = Some(next);
+                    distances.prev[n] = Some(p);
+                    heap.push(Reverse((next + heuristic(n), n)));
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::advent_of_code::{Grid, Neighbourhood, Point, grid_of, lines};
+
+    fn maze() -> Grid {
+        grid_of(&["S..#....", ".#.#.##.", ".#...#..", ".####.#.", "......#E"])
+    }
+
+    #[test]
+    fn bfs_distances_and_path() {
+        let grid = maze();
+        let start = Point::new(0, 0);
+        let goal = Point::new(4, 7);
+        let distances = grid.bfs(start, Neighbourhood::Orthogonal, |&c| c != '#');
+        assert_eq!(distances.get(goal), Some(15));
+        assert_eq!(distances.get(Point::new(0, 3)), None);
+        assert_eq!(distances.map()[start], Some(0));
+
+        let path = distances.path_to(goal).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, goal));
+        assert!(path.iter().all(|&p| grid[p] != '#'));
+
+        let diagonal = grid.bfs(start, Neighbourhood::Moore, |&c| c != '#');
+        assert!(diagonal.get(goal).unwrap() < 15);
+    }
+
+    #[test]
+    fn dijkstra_with_cell_costs() {
+        let grid =
+            Grid::from_lines_with(&lines(&["1163", "1381", "2136"]), |c| c.to_digit(10)).unwrap();
+        let distances = grid.dijkstra_by_cell(Point::new(0, 0), Neighbourhood::Orthogonal, |&c| {
+            Some(c as u64)
+        });
+        let route = distances.route_to(Point::new(2, 3)).unwrap();
+        assert_eq!(route.cost, 13);
+        assert_eq!(route.path.len(), 6);
+
+        // moves to the left are not allowed
+        let distances = grid.dijkstra(Point::new(0, 3), Neighbourhood::Orthogonal, |from, to| {
+            (to.col >= from.col).then_some(1)
+        });
+        assert_eq!(distances.get(Point::new(2, 3)), Some(2));
+        assert_eq!(distances.get(Point::new(0, 0)), None);
+    }
+
+    #[test]
+    fn a_star_matches_dijkstra() {
+        let grid = maze();
+        let start = Point::new(0, 0);
+        let goal = Point::new(4, 7);
+        let cost =
+            |_: Point, to: Point| (grid[to] != '#').then_some(if grid[to] == 'E' { 5 } else { 1 });
+        let route = grid.a_star(start, goal, cost).unwrap();
+        let expected = grid.dijkstra(start, Neighbourhood::Orthogonal, cost);
+        assert_eq!(route.cost, 19);
+        assert_eq!(Some(route.cost), expected.get(goal));
+        assert_eq!(route.path.first(), Some(&start));
+
+        assert_eq!(grid.a_star(start, Point::new(0, 3), cost), None);
+    }
+}
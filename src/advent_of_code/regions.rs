@@ -0,0 +1,194 @@
+use super::{Grid, Neighbourhood, Point};
+
+/// A connected group of cells, see `Grid::label_regions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Index of the region in `Regions::regions` and its label in `Regions::labels`.
+    pub label: usize,
+    /// The first cell of the region in row-major order.
+    pub start: Point,
+    /// Number of cells.
+    pub area: usize,
+    /// Number of cell edges between the region and anything else, the grid border included.
+    pub perimeter: usize,
+    /// Number of straight sides of the outline, the same as its number of corners.
+    pub sides: usize,
+    /// Top left corner of the bounding box.
+    pub min: Point,
+    /// Bottom right corner of the bounding box, inclusive.
+    pub max: Point,
+}
+
+/// The regions of a grid, with the label of every cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// The region of the cell at `p`.
+    pub fn at(&self, p: Point) -> &Region {
+        &self.regions[self.labels[p]]
+    }
+}
+
+impl<T> Grid<T> {
+    /// All cells reachable from `seed` through cells that are `inside`, the seed first.
+    ///
+    /// Empty if the seed itself is not inside.
+    pub fn flood_fill<F>(&self, seed: Point, hood: Neighbourhood, inside: F) -> Vec<Point>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if !inside(&self[seed]) {
+            return Vec::new();
+        }
+        let mut seen = Grid::filled(self.rows(), self.cols(), false);
+        seen[seed] = true;
+        let mut filled = vec![seed];
+        let mut next = 0;
+        while let Some(&p) = filled.get(next) {
+            next += 1;
+            for n in self.neighbours(p, hood) {
+                if !seen[n] && inside(&self[n]) {
+                    seen[n] = true;
+                    filled.push(n);
+                }
+            }
+        }
+        filled
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Splits the grid into regions of connected cells with equal values.
+    ///
+    /// `hood` decides which cells are connected, usually `Orthogonal` or `Moore`.
+    pub fn label_regions(&self, hood: Neighbourhood) -> Regions {
+        const UNLABELED: usize = usize::MAX;
+        let mut labels = Grid::filled(self.rows(), self.cols(), UNLABELED);
+        let mut regions = Vec::new();
+        for (start, value) in self.iter() {
+            if labels[start] != UNLABELED {
+                continue;
+            }
+            let label = regions.len();
+            labels[start] = label;
+            let mut stack = vec![start];
+            let mut region = Region {
+                label,
+                start,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: start,
+                max: start,
+            };
+            while let Some(p) = stack.pop() {
+                region.area += 1;
+                region.min = Point::new(region.min.row.min(p.row), region.min.col.min(p.col));
+                region.max = Point::new(region.max.row.max(p.row), region.max.col.max(p.col));
+                for n in self.neighbours(p, hood) {
+                    if labels[n] == UNLABELED && self[n] == *value {
+                        labels[n] = label;
+                        stack.push(n);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        for (p, &label) in labels.iter() {
+            let (perimeter, corners) = outline(&labels, p, label);
+            regions[label].perimeter += perimeter;
+            regions[label].sides += corners;
+        }
+        Regions { labels, regions }
+    }
+}
+
+// open edges and corners of the outline at the cell `p`
+fn outline(labels: &Grid<usize>, p: Point, label: usize) -> (usize, usize) {
+    let same =
+        |dr: i64, dc: i64| labels.get_signed(p.row as i64 + dr, p.col as i64 + dc) == Some(&label);
+    let perimeter = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .iter()
+        .filter(|&&(dr, dc)| !same(dr, dc))
+        .count();
+    let corners = [(-1, -1), (-1, 1), (1, -1), (1, 1)]
+        .iter()
+        .filter(|&&(dr, dc)| {
+            let (vertical, horizontal) = (same(dr, 0), same(0, dc));
+            // outer corner, or inner corner around a missing diagonal cell
+            (!vertical && !horizontal) || (vertical && horizontal && !same(dr, dc))
+        })
+        .count();
+    (perimeter, corners)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::advent_of_code::{Neighbourhood, Point, grid_of};
+
+    #[test]
+    fn flood_fill_from_seed() {
+        let grid = grid_of(&["..#.", ".##.", "#..."]);
+        let filled = grid.flood_fill(Point::new(0, 0), Neighbourhood::Orthogonal, |&c| c == '.');
+        assert_eq!(filled.len(), 3);
+        assert_eq!(filled[0], Point::new(0, 0));
+
+        let walls = grid.flood_fill(Point::new(0, 2), Neighbourhood::Orthogonal, |&c| c == '#');
+        assert_eq!(walls.len(), 3);
+        let walls = grid.flood_fill(Point::new(0, 2), Neighbourhood::Moore, |&c| c == '#');
+        assert_eq!(walls.len(), 4);
+
+        assert!(
+            grid.flood_fill(Point::new(0, 2), Neighbourhood::Orthogonal, |&c| c == '.')
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn region_statistics() {
+        let grid = grid_of(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let regions = grid.label_regions(Neighbourhood::Orthogonal);
+        let stats: Vec<(char, usize, usize, usize)> = regions
+            .regions
+            .iter()
+            .map(|r| (grid[r.start], r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            stats,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ]
+        );
+        let c = regions.at(Point::new(3, 3));
+        assert_eq!((c.min, c.max), (Point::new(1, 2), Point::new(3, 3)));
+        assert_eq!(regions.labels[(2, 2)], c.label);
+    }
+
+    #[test]
+    fn regions_with_holes_and_diagonals() {
+        let grid = grid_of(&["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"]);
+        let regions = grid.label_regions(Neighbourhood::Orthogonal);
+        assert_eq!(regions.regions.len(), 5);
+        let outer = regions.at(Point::new(0, 0));
+        assert_eq!((outer.area, outer.perimeter, outer.sides), (21, 36, 20));
+
+        let grid = grid_of(&["X.", ".X"]);
+        assert_eq!(
+            grid.label_regions(Neighbourhood::Orthogonal).regions.len(),
+            4
+        );
+        let regions = grid.label_regions(Neighbourhood::Moore);
+        assert_eq!(regions.regions.len(), 2);
+        // the cells only touch at a corner, so the outline is counted per cell
+        assert_eq!((regions.regions[0].area, regions.regions[0].sides), (2, 8));
+    }
+}
@@ -0,0 +1,307 @@
+use std::fmt::{Display, Write};
+use std::io;
+use std::path::Path;
+
+use super::{Grid, Point};
+
+/// A colour with 8 bits per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+
+    /// Colour from `0xRRGGBB`.
+    pub const fn hex(value: u32) -> Rgb {
+        Rgb((value >> 16) as u8, (value >> 8) as u8, value as u8)
+    }
+}
+
+/// Pixels of a rendered grid, written as PPM or PNG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Every cell of the grid as a `scale` x `scale` square in the colour of the cell.
+    pub fn from_grid<T, F>(grid: &Grid<T>, scale: usize, colour: F) -> Self
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let (width, height) = (grid.cols() * scale, grid.rows() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.iter_rows() {
+            let line: Vec<Rgb> = row
+                .iter()
+                .flat_map(|cell| std::iter::repeat_n(colour(cell), scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Paints the cells at `points` of the grid the image was made from, at the same `scale`.
+    ///
+    /// Points outside of the image are skipped, like the highlights of `Grid::to_ansi`.
+    pub fn highlight<I>(&mut self, points: I, scale: usize, colour: Rgb)
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        for p in points {
+            let (top, left) = (p.row * scale, p.col * scale);
+            if top >= self.height || left >= self.width {
+                continue;
+            }
+            let right = (left + scale).min(self.width);
+            for y in top..(top + scale).min(self.height) {
+                self.pixels[y * self.width + left..y * self.width + right].fill(colour);
+            }
+        }
+    }
+
+    fn rgb_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b])
+    }
+
+    /// Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.rgb_bytes());
+        ppm
+    }
+
+    /// PNG with the pixel data in uncompressed deflate blocks.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut scanlines = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for line in self.pixels.chunks(self.width.max(1)) {
+            // filter type None
+            scanlines.push(0);
+            scanlines.extend(line.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, deflate, adaptive filters, not interlaced
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Writes the image as PNG, or as PPM if the path ends in `.ppm`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            _ => self.to_png(),
+        };
+        std::fs::write(path, bytes)
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// zlib stream of stored deflate blocks, which hold at most 65535 bytes each
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const BLOCK: usize = u16::MAX as usize;
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+impl<T: Display> Grid<T> {
+    /// The grid as text with 24-bit ANSI colours, one line per row.
+    ///
+    /// Each cell is printed with `Display` in the colour from `colour`, the cells at `highlights`
+    /// on a `background` colour.
+    pub fn to_ansi<F, I>(&self, colour: F, highlights: I, background: Rgb) -> String
+    where
+        F: Fn(&T) -> Rgb,
+        I: IntoIterator<Item = Point>,
+    {
+        let mut marked = Grid::filled(self.rows(), self.cols(), false);
+        for p in highlights {
+            if let Some(mark) = marked.get_mut(p.row, p.col) {
+                *mark = true;
+            }
+        }
+        let mut out = String::new();
+        for (row, line) in self.iter_rows().enumerate() {
+            for (col, cell) in line.iter().enumerate() {
+                let Rgb(r, g, b) = colour(cell);
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                if marked[(row, col)] {
+                    let Rgb(r, g, b) = background;
+                    let _ = write!(out, "\x1b[48;2;{};{};{}m", r, g, b);
+                }
+                let _ = write!(out, "{}\x1b[0m", cell);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Image, Rgb, adler32, crc32, zlib_stored};
+    use crate::advent_of_code::{Grid, Point, grid_of};
+
+    fn colour(c: &char) -> Rgb {
+        match c {
+            '#' => Rgb::WHITE,
+            _ => Rgb::BLACK,
+        }
+    }
+
+    fn grid() -> Grid {
+        grid_of(&["#.", ".#", "##"])
+    }
+
+    // the payload of a zlib stream of stored blocks
+    fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut at = 2;
+        loop {
+            let last = zlib[at] & 1 == 1;
+            assert_eq!(zlib[at] >> 1, 0, "not a stored block");
+            let len = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]) as usize;
+            let nlen = u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]) as usize;
+            assert_eq!(len, !nlen & 0xffff);
+            data.extend(&zlib[at + 5..at + 5 + len]);
+            at += 5 + len;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(zlib[at..], adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        let data: Vec<u8> = (0..200_000).map(|i| (i * 7) as u8).collect();
+        assert_eq!(inflate_stored(&zlib_stored(&data)), data);
+        assert!(inflate_stored(&zlib_stored(&[])).is_empty());
+    }
+
+    #[test]
+    fn ppm_and_png() {
+        let mut image = Image::from_grid(&grid(), 2, colour);
+        assert_eq!((image.width, image.height), (4, 6));
+        assert_eq!(image.pixel(1, 1), Rgb::WHITE);
+        assert_eq!(image.pixel(2, 1), Rgb::BLACK);
+        image.highlight([Point::new(0, 1)], 2, Rgb::RED);
+        assert_eq!(image.pixel(3, 0), Rgb::RED);
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 6\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 6 * 3);
+
+        let png = image.to_png();
+        assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+        assert_eq!(png[12..16], *b"IHDR");
+        assert_eq!(png[16..29], [0, 0, 0, 4, 0, 0, 0, 6, 8, 2, 0, 0, 0]);
+        assert_eq!(png[29..33], crc32(&png[12..29]).to_be_bytes());
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(png[37..41], *b"IDAT");
+        let scanlines = inflate_stored(&png[41..41 + idat_len]);
+        assert_eq!(scanlines.len(), 6 * (1 + 4 * 3));
+        assert_eq!(scanlines[..7], [0, 255, 255, 255, 255, 255, 255]);
+        assert_eq!(png[png.len() - 12..], *b"\0\0\0\0IEND\xaeB`\x82");
+    }
+
+    #[test]
+    fn highlights_outside_are_skipped() {
+        let mut image = Image::from_grid(&grid(), 2, colour);
+        let before = image.clone();
+        image.highlight([Point::new(0, 2), Point::new(3, 0)], 2, Rgb::RED);
+        assert_eq!(image, before);
+        // a larger scale is cut at the edges
+        image.highlight([Point::new(1, 1)], 3, Rgb::RED);
+        assert_eq!(image.pixel(3, 3), Rgb::RED);
+        assert_eq!(image.pixel(3, 5), Rgb::RED);
+        assert_eq!(image.pixel(2, 3), Rgb::WHITE);
+    }
+
+    #[test]
+    fn ansi_colours() {
+        let ansi = grid().to_ansi(colour, [Point::new(2, 1)], Rgb::BLUE);
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "\x1b[38;2;255;255;255m#\x1b[0m\x1b[38;2;0;0;0m.\x1b[0m"
+        );
+        assert!(lines[2].ends_with("\x1b[38;2;255;255;255m\x1b[48;2;38;139;210m#\x1b[0m"));
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::{InputError, Variant};
+
+/// The answer of a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+    /// The part has no solver (yet).
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<u128> for Answer {
+    /// Numbers beyond `u64` become text.
+    fn from(n: u128) -> Self {
+        u64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as u64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A day of the advent, split into parsing the input and solving both parts on the parsed input.
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError>;
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// The input holding the example of `part`.
+    fn example(&self, _part: u32) -> Variant {
+        Variant::Example
+    }
+}
+
+/// How long parsing the input and solving the part took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Object safe view on a `Solution`, so days with different input types can live in one registry.
+pub trait Puzzle {
+    fn day(&self) -> u32;
+
+    /// Parses `data` and solves the given part, parts other than 1 and 2 are `Answer::Unsolved`.
+    fn solve(&self, part: u32, data: &[String]) -> Result<Answer, InputError>;
+
+    /// Like `solve`, also measuring parsing and solving separately.
+    fn solve_timed(&self, part: u32, data: &[String]) -> Result<(Answer, Timing), InputError>;
+
+    /// The input holding the example of `part`.
+    fn example(&self, part: u32) -> Variant;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn solve(&self, part: u32, data: &[String]) -> Result<Answer, InputError> {
+        self.solve_timed(part, data).map(|(answer, _)| answer)
+    }
+
+    fn solve_timed(&self, part: u32, data: &[String]) -> Result<(Answer, Timing), InputError> {
+        let solve_part = match part {
+            1 => S::part1,
+            2 => S::part2,
+            _ => return Ok((Answer::Unsolved, Timing::default())),
+        };
+        let start = Instant::now();
+        let input = self.parse(data)?;
+        let parsed = Instant::now();
+        let answer = solve_part(self, &input);
+        let timing = Timing {
+            parse: parsed - start,
+            solve: parsed.elapsed(),
+        };
+        Ok((answer, timing))
+    }
+
+    fn example(&self, part: u32) -> Variant {
+        Solution::example(self, part)
+    }
+}
+
+/// Day 42 of the tests, part 1 sums one number per line and part 2 is unsolved.
+#[cfg(test)]
+pub(crate) struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    const DAY: u32 = 42;
+    type Input = Vec<u64>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        data.iter()
+            .enumerate()
+            .map(|(index, line)| super::parse_field(line, line, index))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        input.iter().sum::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, Puzzle, Sum};
+    use crate::advent_of_code::{InputError, Variant, lines};
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(12u32).to_string(), "12");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn puzzle_solve() {
+        let data = lines(&["1", "2", "3"]);
+        let puzzle: Box<dyn Puzzle> = Box::new(Sum);
+        assert_eq!(puzzle.day(), 42);
+        assert_eq!(puzzle.solve(1, &data).unwrap(), Answer::Number(6));
+        assert_eq!(puzzle.solve(2, &data).unwrap(), Answer::Unsolved);
+        assert_eq!(puzzle.solve(3, &data).unwrap(), Answer::Unsolved);
+        assert_eq!(puzzle.example(2), Variant::Example);
+
+        let (answer, _) = puzzle.solve_timed(1, &data).unwrap();
+        assert_eq!(answer, Answer::Number(6));
+    }
+
+    #[test]
+    fn puzzle_solve_parse_error() {
+        let data = vec!["1".to_string(), "-2".to_string()];
+        let err = Sum.solve(1, &data).unwrap_err();
+        assert!(matches!(err, InputError::Parse { line: 2, .. }));
+    }
+}
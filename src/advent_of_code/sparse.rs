@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::{Grid, Neighbourhood, Point};
+
+/// Position in a `SparseGrid`, which may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Coord {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Coord {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Coord { row, col }
+    }
+
+    pub fn manhattan(self, other: Coord) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<Point> for Coord {
+    fn from(p: Point) -> Self {
+        Coord::new(p.row as i64, p.col as i64)
+    }
+}
+
+/// Grid without bounds that only stores the cells that are set.
+///
+/// The grid is 2D only. Day 8's junction boxes are 3D, but they are only compared by distance and
+/// never looked up by position or neighbour, so a plain list serves them better.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    // number of cells in each row and column, the first and last keys are the bounding box
+    rows: BTreeMap<i64, usize>,
+    cols: BTreeMap<i64, usize>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            rows: BTreeMap::new(),
+            cols: BTreeMap::new(),
+        }
+    }
+
+    /// The cells of a dense grid for which `keep` holds.
+    pub fn from_grid<F>(grid: &Grid<T>, keep: F) -> Self
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        grid.iter()
+            .filter(|(_, cell)| keep(cell))
+            .map(|(p, cell)| (Coord::from(p), cell.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        self.cells.contains_key(&c)
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        self.cells.get(&c)
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&c)
+    }
+
+    /// Sets a cell, returning its old value.
+    pub fn insert(&mut self, c: Coord, cell: T) -> Option<T> {
+        let old = self.cells.insert(c, cell);
+        if old.is_none() {
+            *self.rows.entry(c.row).or_default() += 1;
+            *self.cols.entry(c.col).or_default() += 1;
+        }
+        old
+    }
+
+    /// Clears a cell, returning its value.
+    pub fn remove(&mut self, c: Coord) -> Option<T> {
+        let cell = self.cells.remove(&c)?;
+        uncount(&mut self.rows, c.row);
+        uncount(&mut self.cols, c.col);
+        Some(cell)
+    }
+
+    /// Top left and bottom right corner of the smallest box around all cells, `None` without cells.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        let (&min_row, _) = self.rows.first_key_value()?;
+        let (&max_row, _) = self.rows.last_key_value()?;
+        let (&min_col, _) = self.cols.first_key_value()?;
+        let (&max_col, _) = self.cols.last_key_value()?;
+        Some((Coord::new(min_row, min_col), Coord::new(max_row, max_col)))
+    }
+
+    /// All cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&c, cell)| (c, cell))
+    }
+
+    /// Positions of the neighbours of `c`, set or not.
+    ///
+    /// Unlike `Grid::neighbours`, which only yields positions inside the grid, every position
+    /// exists on an unbounded grid. `neighbour_cells` yields only the set ones.
+    pub fn neighbour_positions(
+        &self,
+        c: Coord,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = Coord> + use<T> {
+        hood.deltas()
+            .map(move |(dr, dc)| Coord::new(c.row + dr, c.col + dc))
+    }
+
+    /// The neighbours of `c` that are set, with their cells.
+    pub fn neighbour_cells(
+        &self,
+        c: Coord,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        self.neighbour_positions(c, hood)
+            .filter_map(move |n| self.get(n).map(|cell| (n, cell)))
+    }
+
+    /// A dense grid of the bounding box with `fill` for the cells that are not set,
+    /// and the position of its top left cell.
+    pub fn to_grid(&self, fill: T) -> (Grid<T>, Coord)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return (Grid::from_vec(0, 0, Vec::new()), Coord::default());
+        };
+        let rows = (max.row - min.row + 1) as usize;
+        let cols = (max.col - min.col + 1) as usize;
+        let mut grid = Grid::filled(rows, cols, fill);
+        for (c, cell) in self.iter() {
+            grid[Point::new((c.row - min.row) as usize, (c.col - min.col) as usize)] = cell.clone();
+        }
+        (grid, min)
+    }
+}
+
+// Drops one cell from the count of its row or column, and the row or column once it is empty.
+fn uncount(counts: &mut BTreeMap<i64, usize>, key: i64) {
+    if let Some(count) = counts.get_mut(&key) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(&key);
+        }
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (c, cell) in iter {
+            grid.insert(c, cell);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coord, SparseGrid};
+    use crate::advent_of_code::{Neighbourhood, grid_of};
+
+    #[test]
+    fn bounds_follow_the_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Coord::new(3, -2), 'a');
+        grid.insert(Coord::new(-100_000, 7), 'b');
+        grid.insert(Coord::new(0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some((Coord::new(-100_000, -2), Coord::new(3, 7)))
+        );
+        assert_eq!(grid.insert(Coord::new(0, 0), 'd'), Some('c'));
+        assert_eq!(grid.len(), 3);
+
+        assert_eq!(grid.remove(Coord::new(-100_000, 7)), Some('b'));
+        assert_eq!(grid.bounds(), Some((Coord::new(0, -2), Coord::new(3, 0))));
+        assert_eq!(grid.remove(Coord::new(5, 5)), None);
+        grid.remove(Coord::new(3, -2));
+        grid.remove(Coord::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+        assert_eq!(grid, SparseGrid::new());
+    }
+
+    #[test]
+    fn bounds_shrink_only_with_the_last_cell_of_an_edge() {
+        let mut grid: SparseGrid<()> = [(0, 0), (0, 5), (4, 5), (4, 1)]
+            .into_iter()
+            .map(|(row, col)| (Coord::new(row, col), ()))
+            .collect();
+        grid.remove(Coord::new(0, 0));
+        assert_eq!(grid.bounds(), Some((Coord::new(0, 1), Coord::new(4, 5))));
+        grid.remove(Coord::new(0, 5));
+        assert_eq!(grid.bounds(), Some((Coord::new(4, 1), Coord::new(4, 5))));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid: SparseGrid<u8> = [(Coord::new(-1, 0), 1), (Coord::new(1, 1), 2)]
+            .into_iter()
+            .collect();
+        let origin = Coord::new(0, 0);
+        assert_eq!(
+            grid.neighbour_positions(origin, Neighbourhood::Orthogonal)
+                .count(),
+            4
+        );
+        let cells: Vec<_> = grid.neighbour_cells(origin, Neighbourhood::Moore).collect();
+        assert_eq!(cells, [(Coord::new(-1, 0), &1), (Coord::new(1, 1), &2)]);
+        assert_eq!(
+            grid.neighbour_cells(origin, Neighbourhood::Orthogonal)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn dense_conversion() {
+        let dense = grid_of(&["..#", "#..", "..."]);
+        let mut sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(Coord::new(0, 2)), Some(&'#'));
+
+        sparse.insert(Coord::new(-1, -1), '#');
+        let (grid, origin) = sparse.to_grid('.');
+        assert_eq!(origin, Coord::new(-1, -1));
+        assert_eq!(grid, grid_of(&["#...", "...#", ".#.."]));
+
+        let (empty, _) = SparseGrid::<char>::new().to_grid('.');
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+    }
+}
@@ -0,0 +1,200 @@
+use super::{Grid, Point};
+
+/// One of the 8 ways to rotate or mirror a grid onto itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// A quarter turn clockwise.
+    Rotate90,
+    Rotate180,
+    /// A quarter turn counter-clockwise.
+    Rotate270,
+    /// Mirrors left and right.
+    FlipHorizontal,
+    /// Mirrors top and bottom.
+    FlipVertical,
+    /// Mirrors along the main diagonal, rows become columns.
+    Transpose,
+    /// Mirrors along the other diagonal.
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// Whether rows and columns trade places.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Symmetry::Rotate90
+                | Symmetry::Rotate270
+                | Symmetry::Transpose
+                | Symmetry::AntiTranspose
+        )
+    }
+
+    // position in a `rows` x `cols` grid that ends up at `(row, col)` of the transformed one
+    fn source(self, row: usize, col: usize, rows: usize, cols: usize) -> Point {
+        let (r, c) = match self {
+            Symmetry::Identity => (row, col),
+            Symmetry::Rotate90 => (rows - 1 - col, row),
+            Symmetry::Rotate180 => (rows - 1 - row, cols - 1 - col),
+            Symmetry::Rotate270 => (col, cols - 1 - row),
+            Symmetry::FlipHorizontal => (row, cols - 1 - col),
+            Symmetry::FlipVertical => (rows - 1 - row, col),
+            Symmetry::Transpose => (col, row),
+            Symmetry::AntiTranspose => (rows - 1 - col, cols - 1 - row),
+        };
+        Point::new(r, c)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A rotated or mirrored copy of the grid.
+    pub fn transform(&self, symmetry: Symmetry) -> Self {
+        let (rows, cols) = match symmetry.swaps_axes() {
+            true => (self.cols(), self.rows()),
+            false => (self.rows(), self.cols()),
+        };
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|(row, col)| self[symmetry.source(row, col, self.rows(), self.cols())].clone())
+            .collect();
+        Grid::from_vec(rows, cols, cells)
+    }
+
+    /// All 8 rotations and mirrors of the grid, in the order of `Symmetry::ALL`.
+    pub fn symmetries(&self) -> impl Iterator<Item = (Symmetry, Self)> {
+        Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| (symmetry, self.transform(symmetry)))
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.transform(Symmetry::Transpose)
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.transform(Symmetry::Rotate90)
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.transform(Symmetry::Rotate180)
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.transform(Symmetry::Rotate270)
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        self.transform(Symmetry::FlipHorizontal)
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        self.transform(Symmetry::FlipVertical)
+    }
+
+    /// The `rows` x `cols` window with `top_left` as its first cell, `None` if it does not fit in the grid.
+    pub fn crop(&self, top_left: Point, rows: usize, cols: usize) -> Option<Self> {
+        if top_left.row + rows > self.rows() || top_left.col + cols > self.cols() {
+            return None;
+        }
+        let cells = (top_left.row..top_left.row + rows)
+            .flat_map(|row| {
+                self.row(row)[top_left.col..top_left.col + cols]
+                    .iter()
+                    .cloned()
+            })
+            .collect();
+        Some(Grid::from_vec(rows, cols, cells))
+    }
+
+    /// Copies `other` into the grid with its first cell at `top_left`, cutting off what does not fit.
+    pub fn stamp(&mut self, top_left: Point, other: &Grid<T>) {
+        for (p, cell) in other.iter() {
+            if let Some(target) = self.get_mut(top_left.row + p.row, top_left.col + p.col) {
+                *target = cell.clone();
+            }
+        }
+    }
+
+    /// The grid repeated `down` times vertically and `across` times horizontally.
+    pub fn tile(&self, down: usize, across: usize) -> Self {
+        let cells = (0..down * self.rows())
+            .flat_map(|row| {
+                let row = self.row(row % self.rows());
+                (0..across).flat_map(move |_| row.iter().cloned())
+            })
+            .collect();
+        Grid::from_vec(down * self.rows(), across * self.cols(), cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Symmetry;
+    use crate::advent_of_code::{Grid, Point, grid_of};
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = grid_of(&["abc", "def"]);
+        assert_eq!(grid.transpose(), grid_of(&["ad", "be", "cf"]));
+        assert_eq!(grid.rotate_cw(), grid_of(&["da", "eb", "fc"]));
+        assert_eq!(grid.rotate_180(), grid_of(&["fed", "cba"]));
+        assert_eq!(grid.rotate_ccw(), grid_of(&["cf", "be", "ad"]));
+        assert_eq!(grid.flip_horizontal(), grid_of(&["cba", "fed"]));
+        assert_eq!(grid.flip_vertical(), grid_of(&["def", "abc"]));
+        assert_eq!(
+            grid.transform(Symmetry::AntiTranspose),
+            grid_of(&["fc", "eb", "da"])
+        );
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+
+        let empty = Grid::<char>::from_lines(&[]).unwrap();
+        assert_eq!(empty.transpose(), empty);
+    }
+
+    #[test]
+    fn all_symmetries() {
+        let grid = grid_of(&["ab", "cd"]);
+        let images: Vec<Grid> = grid.symmetries().map(|(_, g)| g).collect();
+        assert_eq!(images.len(), 8);
+        for (i, image) in images.iter().enumerate() {
+            assert!(
+                !images[..i].contains(image),
+                "{:?} repeats",
+                Symmetry::ALL[i]
+            );
+        }
+
+        let square = grid_of(&["..", ".."]);
+        assert!(square.symmetries().all(|(_, g)| g == square));
+    }
+
+    #[test]
+    fn crop_stamp_and_tile() {
+        let grid = grid_of(&["abcd", "efgh", "ijkl"]);
+        assert_eq!(
+            grid.crop(Point::new(1, 1), 2, 2),
+            Some(grid_of(&["fg", "jk"]))
+        );
+        assert_eq!(grid.crop(Point::new(2, 0), 2, 1), None);
+        assert_eq!(grid.crop(Point::new(0, 4), 1, 0).map(|g| g.cols()), Some(0));
+
+        let mut canvas = Grid::filled(3, 4, '.');
+        canvas.stamp(Point::new(1, 2), &grid_of(&["xyz", "uvw"]));
+        assert_eq!(canvas, grid_of(&["....", "..xy", "..uv"]));
+
+        assert_eq!(grid_of(&["ab"]).tile(2, 3), grid_of(&["ababab", "ababab"]));
+    }
+}
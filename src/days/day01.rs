@@ -0,0 +1,417 @@
+use std::fmt::{self, Write};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::advent_of_code::{Answer, InputError, Solution, parse_field};
+
+use super::{day01_1, day01_2};
+
+/// Day 1 with the dial the rotations are applied to.
+pub struct Day01 {
+    pub dial: Dial,
+}
+
+/// Number of positions on the puzzle's dial, 0 to 99.
+pub const DIAL_SIZE: u32 = 100;
+/// Position the puzzle's dial points at before the first rotation.
+pub const DIAL_START: u32 = 50;
+
+/// One line of the document, turning the dial by a number of clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Towards lower numbers.
+    Left(u32),
+    /// Towards higher numbers.
+    Right(u32),
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rotation::Left(distance) => write!(f, "L{}", distance),
+            Rotation::Right(distance) => write!(f, "R{}", distance),
+        }
+    }
+}
+
+impl Rotation {
+    pub fn distance(self) -> u32 {
+        match self {
+            Rotation::Left(distance) | Rotation::Right(distance) => distance,
+        }
+    }
+
+    // example str: "L68", `index` is the line index for error reporting
+    fn parse_line(s: &str, index: usize) -> Result<Self, InputError> {
+        let Some(direction) = s.chars().next() else {
+            return Err(InputError::parse(
+                index,
+                0,
+                "expected a rotation like 'L68', found an empty line",
+            ));
+        };
+        let distance = &s[direction.len_utf8()..];
+        if distance.is_empty() {
+            return Err(InputError::parse(index, 1, "missing the number of clicks"));
+        }
+        if let Some((column, c)) = distance
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_digit())
+        {
+            return Err(InputError::parse(
+                index,
+                1 + column,
+                format!("unexpected character '{}' in the number of clicks", c),
+            ));
+        }
+        let distance = parse_field(s, distance, index)?;
+        match direction {
+            'L' => Ok(Rotation::Left(distance)),
+            'R' => Ok(Rotation::Right(distance)),
+            _ => Err(InputError::parse(
+                index,
+                0,
+                format!("expected 'L' or 'R', found '{}'", direction),
+            )),
+        }
+    }
+}
+
+/// A dial numbered 0 to `size - 1` with the numbers wrapping around, and the positions to count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u32,
+    position: u32,
+    // sorted and distinct
+    targets: Vec<u32>,
+}
+
+impl Default for Dial {
+    /// The dial of the puzzle.
+    fn default() -> Self {
+        Dial::new(DIAL_SIZE, DIAL_START)
+    }
+}
+
+impl Dial {
+    /// A dial with `size` positions pointing at `start`, with 0 as its only target.
+    pub fn new(size: u32, start: u32) -> Self {
+        assert!(
+            start < size,
+            "start {} is not on a dial of size {}",
+            start,
+            size
+        );
+        Dial {
+            size,
+            position: start,
+            targets: vec![0],
+        }
+    }
+
+    /// Replaces the positions counted by `turn`, wrapping them onto the dial.
+    pub fn with_targets<I>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        self.targets = targets.into_iter().map(|t| t % self.size).collect();
+        self.targets.sort_unstable();
+        self.targets.dedup();
+        self
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn targets(&self) -> &[u32] {
+        &self.targets
+    }
+
+    pub fn on_target(&self) -> bool {
+        self.targets.binary_search(&self.position).is_ok()
+    }
+
+    /// Turns the dial and returns how many clicks left it pointing at a target, the last one included.
+    pub fn turn(&mut self, rotation: Rotation) -> u64 {
+        let (size, distance) = (self.size as u64, rotation.distance() as u64);
+        let position = self.position as u64;
+        let hits = self
+            .targets
+            .iter()
+            .map(|&target| {
+                // clicks from the position to the first time at the target
+                let to_target = match rotation {
+                    Rotation::Right(_) => (target as u64 + size - position) % size,
+                    Rotation::Left(_) => (position + size - target as u64) % size,
+                };
+                let to_target = if to_target == 0 { size } else { to_target };
+                match distance.checked_sub(to_target) {
+                    Some(rest) => 1 + rest / size,
+                    None => 0,
+                }
+            })
+            .sum();
+        let end = match rotation {
+            Rotation::Right(_) => (position + distance) % size,
+            Rotation::Left(_) => (position + size - distance % size) % size,
+        };
+        self.position = end as u32;
+        hits
+    }
+
+    /// Like `turn`, one click at a time, recording every position in `trace`.
+    pub fn turn_traced(&mut self, rotation: Rotation, trace: &mut Trace) -> u64 {
+        trace.rotations += 1;
+        let mut hits = 0;
+        for click in 1..=rotation.distance() {
+            self.position = match rotation {
+                Rotation::Right(_) => (self.position + 1) % self.size,
+                Rotation::Left(_) => (self.position + self.size - 1) % self.size,
+            };
+            let on_target = self.on_target();
+            hits += on_target as u64;
+            trace.steps.push(Step {
+                rotation: trace.rotations,
+                instruction: Some(rotation),
+                click,
+                position: self.position,
+                on_target,
+            });
+        }
+        hits
+    }
+}
+
+impl FromStr for Dial {
+    type Err = String;
+
+    // example str: "100,50" or "100,50,0,25", the size, the start and the targets if not only 0
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s
+            .split(',')
+            .map(|n| {
+                n.parse::<u32>()
+                    .map_err(|_| format!("invalid number '{}' in dial '{}'", n, s))
+            })
+            .collect::<Result<Vec<u32>, String>>()?;
+        let [size, start, targets @ ..] = numbers.as_slice() else {
+            return Err(format!("dial '{}' needs <size>,<start>[,<target>...]", s));
+        };
+        if start >= size {
+            return Err(format!("start {} is not on a dial of size {}", start, size));
+        }
+        let dial = Dial::new(*size, *start);
+        Ok(match targets {
+            [] => dial,
+            targets => dial.with_targets(targets.iter().copied()),
+        })
+    }
+}
+
+/// The position of a dial after one click.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// Number of the rotation, 0 for the start position.
+    pub rotation: usize,
+    pub instruction: Option<Rotation>,
+    /// Number of the click within the rotation.
+    pub click: u32,
+    pub position: u32,
+    pub on_target: bool,
+}
+
+/// Every position of a dial, recorded by `Dial::turn_traced`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    steps: Vec<Step>,
+    rotations: usize,
+}
+
+impl Trace {
+    /// A trace starting at the current position of the dial.
+    pub fn new(dial: &Dial) -> Self {
+        Trace {
+            steps: vec![Step {
+                rotation: 0,
+                instruction: None,
+                click: 0,
+                position: dial.position(),
+                on_target: dial.on_target(),
+            }],
+            rotations: 0,
+        }
+    }
+
+    /// Applies all rotations to a copy of `dial`, one click at a time.
+    pub fn record(dial: &Dial, rotations: &[Rotation]) -> Self {
+        let mut dial = dial.clone();
+        let mut trace = Trace::new(&dial);
+        for &rotation in rotations {
+            dial.turn_traced(rotation, &mut trace);
+        }
+        trace
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// One line per step, after a header line.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("rotation,instruction,click,position,on_target\n");
+        for step in &self.steps {
+            let instruction = step.instruction.map(|r| r.to_string()).unwrap_or_default();
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                step.rotation, instruction, step.click, step.position, step.on_target
+            );
+        }
+        csv
+    }
+
+    pub fn write_csv(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_csv())
+    }
+}
+
+/// Parses one rotation per line, rejecting anything but 'L' or 'R' followed by digits.
+pub fn parse(data: &[String]) -> Result<Vec<Rotation>, InputError> {
+    data.iter()
+        .enumerate()
+        .map(|(index, line)| Rotation::parse_line(line, index))
+        .collect()
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<Rotation>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day01_1::puzzle(&self.dial, input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day01_2::puzzle(&self.dial, input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dial, Rotation, Trace, parse};
+    use crate::advent_of_code::lines;
+
+    #[test]
+    fn turn_counts_every_target() {
+        let dials = [
+            Dial::default(),
+            Dial::new(7, 3).with_targets([0, 4, 11]),
+            Dial::new(1, 0),
+        ];
+        for dial in dials {
+            for start in 0..dial.size() {
+                for distance in [0, 1, 3, 6, 7, 8, 50, 99, 100, 101, 250, 1000] {
+                    for rotation in [Rotation::Left(distance), Rotation::Right(distance)] {
+                        let mut fast = Dial {
+                            position: start,
+                            ..dial.clone()
+                        };
+                        let mut slow = fast.clone();
+                        let hits = fast.turn(rotation);
+                        let mut trace = Trace::new(&slow);
+                        let slow_hits = slow.turn_traced(rotation, &mut trace);
+                        assert_eq!(
+                            (fast.position(), hits),
+                            (slow.position(), slow_hits),
+                            "{} from {} on {:?}",
+                            rotation,
+                            start,
+                            dial
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn targets_wrap_onto_the_dial() {
+        let dial = Dial::new(10, 0).with_targets([12, 2, 5]);
+        assert_eq!(dial.targets(), [2, 5]);
+        assert!(!dial.on_target());
+    }
+
+    #[test]
+    fn dial_from_str() {
+        assert_eq!("100,50".parse(), Ok(Dial::default()));
+        assert_eq!(
+            "10,3,12,2,5".parse(),
+            Ok(Dial::new(10, 3).with_targets([2, 5]))
+        );
+        assert_eq!(
+            "10".parse::<Dial>().unwrap_err(),
+            "dial '10' needs <size>,<start>[,<target>...]"
+        );
+        assert_eq!(
+            "10,10".parse::<Dial>().unwrap_err(),
+            "start 10 is not on a dial of size 10"
+        );
+        assert_eq!(
+            "10,-1".parse::<Dial>().unwrap_err(),
+            "invalid number '-1' in dial '10,-1'"
+        );
+    }
+
+    #[test]
+    fn trace_as_csv() {
+        let dial = Dial::new(4, 1);
+        let trace = Trace::record(&dial, &[Rotation::Left(2), Rotation::Right(1)]);
+        assert_eq!(
+            trace.to_csv(),
+            "rotation,instruction,click,position,on_target\n\
+             0,,0,1,false\n\
+             1,L2,1,0,true\n\
+             1,L2,2,3,false\n\
+             2,R1,1,0,true\n"
+        );
+        assert_eq!(trace.steps().len(), 4);
+    }
+
+    #[test]
+    fn parse_rotations() {
+        assert_eq!(
+            parse(&lines(&["L68", "R1000", "L0"])).unwrap(),
+            [Rotation::Left(68), Rotation::Right(1000), Rotation::Left(0)]
+        );
+        let error = |line: &str| parse(&lines(&["L1", line])).unwrap_err().to_string();
+        assert_eq!(
+            error("X5"),
+            "line 2, column 1: expected 'L' or 'R', found 'X'"
+        );
+        assert_eq!(
+            error(""),
+            "line 2, column 1: expected a rotation like 'L68', found an empty line"
+        );
+        assert_eq!(error("R"), "line 2, column 2: missing the number of clicks");
+        assert_eq!(
+            error("R+5"),
+            "line 2, column 2: unexpected character '+' in the number of clicks"
+        );
+        assert_eq!(
+            error("L12 "),
+            "line 2, column 4: unexpected character ' ' in the number of clicks"
+        );
+        assert!(error("R99999999999").contains("cannot parse '99999999999'"));
+    }
+}
@@ -0,0 +1,13 @@
+use super::day01::{Dial, Rotation};
+
+// counts the rotations that leave the dial on a target
+pub fn puzzle(dial: &Dial, rotations: &[Rotation]) -> u32 {
+    let mut dial = dial.clone();
+    rotations
+        .iter()
+        .filter(|&&rotation| {
+            dial.turn(rotation);
+            dial.on_target()
+        })
+        .count() as u32
+}
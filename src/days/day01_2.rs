@@ -0,0 +1,7 @@
+use super::day01::{Dial, Rotation};
+
+// counts every click that leaves the dial on a target, also in the middle of a rotation
+pub fn puzzle(dial: &Dial, rotations: &[Rotation]) -> u64 {
+    let mut dial = dial.clone();
+    rotations.iter().map(|&rotation| dial.turn(rotation)).sum()
+}
@@ -0,0 +1,562 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::advent_of_code::{Answer, InputError, Solution};
+use crate::{debug, trace};
+
+use super::{day02_1, day02_2};
+
+/// Day 2 with the rule telling invalid IDs apart.
+pub struct Day02 {
+    /// Replaces the rules of both parts if set.
+    pub rule: Option<IdRule>,
+}
+
+/// The shape of the digits of an invalid ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    /// One block of digits repeated `k` times, like 1212 or 1111 for 2 times. `k` is at least 2.
+    Exactly(u32),
+    /// One block of digits repeated `k` or more times, like 1212 or 121212 for 2. `k` is at
+    /// least 2.
+    AtLeast(u32),
+    /// The digits read the same backwards, like 7 or 12321.
+    Palindrome,
+}
+
+/// Which IDs are invalid: those whose digits in `base` follow the `pattern`.
+///
+/// Written as `exactly:<k>`, `at-least:<k>` or `palindrome`, followed by `@<base>` for bases other
+/// than 10, e.g. `at-least:3@16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRule {
+    pub pattern: Pattern,
+    pub base: u32,
+}
+
+impl IdRule {
+    /// The rule of part 1.
+    pub const TWICE: IdRule = IdRule::new(Pattern::Exactly(2));
+    /// The rule of part 2.
+    pub const AT_LEAST_TWICE: IdRule = IdRule::new(Pattern::AtLeast(2));
+
+    /// A rule on decimal digits.
+    pub const fn new(pattern: Pattern) -> Self {
+        IdRule { pattern, base: 10 }
+    }
+
+    pub fn in_base(self, base: u32) -> Self {
+        assert!(base >= 2, "base {} has no digits", base);
+        IdRule { base, ..self }
+    }
+
+    /// Checks a single ID digit by digit.
+    pub fn matches(&self, id: u64) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = id;
+        loop {
+            digits.push(rest % self.base as u64);
+            rest /= self.base as u64;
+            if rest == 0 {
+                break;
+            }
+        }
+        let len = digits.len() as u32;
+        let repeated = |r: u32| {
+            len.is_multiple_of(r)
+                && digits
+                    .chunks((len / r) as usize)
+                    .all(|block| block == &digits[..(len / r) as usize])
+        };
+        match self.pattern {
+            Pattern::Exactly(k) => k >= 2 && repeated(k),
+            Pattern::AtLeast(k) => (k.max(2)..=len).any(repeated),
+            Pattern::Palindrome => digits.iter().eq(digits.iter().rev()),
+        }
+    }
+
+    /// Number and sum of the invalid IDs in `lower..=upper`.
+    ///
+    /// Nothing is enumerated, the time only grows with the number of digits of `upper`.
+    pub fn invalid_ids(&self, lower: u64, upper: u64) -> (u128, u128) {
+        match self.pattern {
+            Pattern::Exactly(k) => repeated_ids(lower, upper, self.base, |r| r == k),
+            Pattern::AtLeast(k) => repeated_ids(lower, upper, self.base, |r| r >= k),
+            Pattern::Palindrome => palindromes(lower, upper, self.base),
+        }
+    }
+}
+
+impl fmt::Display for IdRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.pattern {
+            Pattern::Exactly(k) => write!(f, "exactly:{}", k)?,
+            Pattern::AtLeast(k) => write!(f, "at-least:{}", k)?,
+            Pattern::Palindrome => write!(f, "palindrome")?,
+        }
+        if self.base != 10 {
+            write!(f, "@{}", self.base)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IdRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, base) = match s.split_once('@') {
+            Some((pattern, base)) => match base.parse::<u32>() {
+                Ok(base) if base >= 2 => (pattern, base),
+                _ => return Err(format!("invalid base '{}', expected 2 or more", base)),
+            },
+            None => (s, 10),
+        };
+        let repeats = |k: &str| match k.parse::<u32>() {
+            Ok(k) if k >= 2 => Ok(k),
+            _ => Err(format!("invalid repeat count '{}', expected 2 or more", k)),
+        };
+        let pattern = match pattern.split_once(':') {
+            Some(("exactly", k)) => Pattern::Exactly(repeats(k)?),
+            Some(("at-least", k)) => Pattern::AtLeast(repeats(k)?),
+            None if pattern == "palindrome" => Pattern::Palindrome,
+            _ => {
+                return Err(format!(
+                    "unknown rule '{}', expected exactly:<k>, at-least:<k> or palindrome",
+                    pattern
+                ));
+            }
+        };
+        Ok(IdRule { pattern, base })
+    }
+}
+
+/// Sums all invalid IDs found in the intervals, `None` if the sum overflows `u128`.
+pub fn sum_invalid_ids(intervals: &[(u64, u64)], rule: &IdRule) -> Option<u128> {
+    let mut total_count = 0u128;
+    let mut sum = 0u128;
+
+    for &(lower, upper) in intervals {
+        let (count, interval_sum) = rule.invalid_ids(lower, upper);
+        trace!("Interval {}-{}: found {} numbers", lower, upper, count);
+        total_count = total_count.saturating_add(count);
+        sum = sum.checked_add(interval_sum)?;
+    }
+
+    debug!("Total numbers across all intervals: {}", total_count);
+    Some(sum)
+}
+
+// Number and sum of the IDs in `lower..=upper` whose digits are one block repeated `r` times, for
+// some `r` accepted by `repeats`.
+//
+// Nothing is enumerated: for every digit length the numbers of each repeat period form an
+// arithmetic sequence, and an ID repeating with several periods is counted once.
+fn repeated_ids<F>(lower: u64, upper: u64, base: u32, repeats: F) -> (u128, u128)
+where
+    F: Fn(u32) -> bool,
+{
+    let (mut count, mut sum) = (0, 0);
+    for len in digits(lower, base)..=digits(upper, base) {
+        let Some((lo, hi)) = clamp_to_length(lower, upper, base, len) else {
+            continue;
+        };
+        // Inclusion–exclusion over the periods dividing `len`: `exact[i]` holds the IDs whose
+        // shortest period is `periods[i]`, which are all periodic IDs minus those with a shorter
+        // period dividing it. An ID with shortest period `p` is a block repeated `r` times for
+        // every `r` dividing `len / p`.
+        let periods: Vec<u32> = (1..len).filter(|p| len.is_multiple_of(*p)).collect();
+        let mut exact: Vec<(u128, u128)> = Vec::with_capacity(periods.len());
+        for (i, &period) in periods.iter().enumerate() {
+            let (mut c, mut s) = periodic(lo, hi, base, len, period);
+            for (j, &shorter) in periods[..i].iter().enumerate() {
+                if period.is_multiple_of(shorter) {
+                    c -= exact[j].0;
+                    s -= exact[j].1;
+                }
+            }
+            exact.push((c, s));
+            let blocks = len / period;
+            if (2..=blocks).any(|r| blocks.is_multiple_of(r) && repeats(r)) {
+                count += c;
+                sum += s;
+            }
+        }
+    }
+    (count, sum)
+}
+
+fn digits(n: u64, base: u32) -> u32 {
+    n.checked_ilog(base as u64).unwrap_or(0) + 1
+}
+
+// the part of `lower..=upper` with `len` digits
+fn clamp_to_length(lower: u64, upper: u64, base: u32, len: u32) -> Option<(u128, u128)> {
+    let base = base as u128;
+    // 0 is the only number with a leading zero
+    let shortest = if len == 1 { 0 } else { base.pow(len - 1) };
+    let lo = (lower as u128).max(shortest);
+    let hi = (upper as u128).min(base.pow(len) - 1);
+    (lo <= hi).then_some((lo, hi))
+}
+
+// number and sum of the `len`-digit IDs in `lo..=hi` made of a `period`-digit block repeated,
+// which are the blocks times 1 0..0 1 0..0 1 with the ones `period` digits apart
+fn periodic(lo: u128, hi: u128, base: u32, len: u32, period: u32) -> (u128, u128) {
+    let base = base as u128;
+    let factor = (base.pow(len) - 1) / (base.pow(period) - 1);
+    let first = lo.div_ceil(factor).max(base.pow(period - 1));
+    let last = (hi / factor).min(base.pow(period) - 1);
+    if first > last {
+        return (0, 0);
+    }
+    let count = last - first + 1;
+    (count, factor * ((first + last) * count / 2))
+}
+
+// Number and sum of the palindromes in `lower..=upper`, built from the first half of their digits.
+//
+// Nothing is enumerated either: for every digit length the palindromes grow with their first
+// half, so those in the interval come from one range of halves. Their sum is the halves shifted
+// left plus the mirrored digits, and the mirrored digits are sums of single digits over the range.
+fn palindromes(lower: u64, upper: u64, base: u32) -> (u128, u128) {
+    let (mut count, mut sum) = (0, 0);
+    for len in digits(lower, base)..=digits(upper, base) {
+        let Some((lo, hi)) = clamp_to_length(lower, upper, base, len) else {
+            continue;
+        };
+        let b = base as u128;
+        // the first half includes the middle digit of odd lengths
+        let mirrored = len / 2;
+        let shift = b.pow(mirrored);
+        let mut first = lo / shift;
+        if palindrome(first, base, len) < lo {
+            first += 1;
+        }
+        let mut last = hi / shift;
+        if palindrome(last, base, len) > hi {
+            last -= 1;
+        }
+        if first > last {
+            continue;
+        }
+        count += last - first + 1;
+        sum += shift * ((first + last) * (last - first + 1) / 2);
+        // digit `len % 2 + i` of the half ends up as digit `mirrored - 1 - i` of the palindrome
+        for i in 0..mirrored {
+            let position = len % 2 + i;
+            let digit_sum =
+                digit_sum_below(last + 1, b, position) - digit_sum_below(first, b, position);
+            sum += b.pow(mirrored - 1 - i) * digit_sum;
+        }
+    }
+    (count, sum)
+}
+
+// the `len`-digit palindrome whose first half is `half`
+fn palindrome(half: u128, base: u32, len: u32) -> u128 {
+    let b = base as u128;
+    let mirrored = len / 2;
+    let mut palindrome = half;
+    let mut rest = half / b.pow(len - 2 * mirrored);
+    for _ in 0..mirrored {
+        palindrome = palindrome * b + rest % b;
+        rest /= b;
+    }
+    palindrome
+}
+
+// sum of the digit at `position` of all numbers below `n`, the digits cycle through 0 to base - 1
+// every `base^(position + 1)` numbers
+fn digit_sum_below(n: u128, base: u128, position: u32) -> u128 {
+    let run = base.pow(position);
+    let (cycles, rest) = (n / (run * base), n % (run * base));
+    let (digit, partial) = (rest / run, rest % run);
+    cycles * run * (base * (base - 1) / 2)
+        + run * (digit * digit.saturating_sub(1) / 2)
+        + digit * partial
+}
+
+// one interval of the list, which may be wrapped over several lines
+#[derive(Default)]
+struct Field {
+    text: String,
+    // line and column index, in characters, of every byte of `text`
+    positions: Vec<(usize, usize)>,
+}
+
+impl Field {
+    fn push(&mut self, c: char, line_index: usize, column: usize) {
+        self.text.push(c);
+        self.positions
+            .extend(std::iter::repeat_n((line_index, column), c.len_utf8()));
+    }
+
+    fn error(&self, offset: usize, message: String) -> InputError {
+        let (line_index, column) = self.positions[offset];
+        InputError::parse(line_index, column, message)
+    }
+
+    // `number` starts at byte `offset` of the field
+    fn parse_number(&self, number: &str, offset: usize) -> Result<u64, InputError> {
+        number
+            .parse()
+            .map_err(|e| self.error(offset, format!("cannot parse '{}': {}", number, e)))
+    }
+
+    // "11-22", with whitespace around it
+    fn parse(&self) -> Result<(u64, u64), InputError> {
+        let interval = self.text.trim();
+        let start = self.text.len() - self.text.trim_start().len();
+        let malformed = || {
+            self.error(
+                start,
+                format!("expected an interval like '11-22', found '{}'", interval),
+            )
+        };
+        let (lower, upper) = interval.split_once('-').ok_or_else(malformed)?;
+        if lower.is_empty() || upper.is_empty() || upper.contains('-') {
+            return Err(malformed());
+        }
+        let lower_id = self.parse_number(lower, start)?;
+        let upper_id = self.parse_number(upper, start + lower.len() + 1)?;
+        if lower_id > upper_id {
+            return Err(self.error(
+                start,
+                format!("interval '{}' ends before it starts", interval),
+            ));
+        }
+        Ok((lower_id, upper_id))
+    }
+}
+
+/// Parses the comma-separated list of ID intervals like "11-22,95-115".
+///
+/// The list may be wrapped over any number of lines, a line break continues the interval it
+/// interrupts. Empty entries, like after a trailing comma, and whitespace around an interval are
+/// ignored.
+pub fn parse(data: &[String]) -> Result<Vec<(u64, u64)>, InputError> {
+    let mut fields = vec![Field::default()];
+    for (line_index, line) in data.iter().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            match c {
+                ',' => fields.push(Field::default()),
+                c => fields.last_mut().unwrap().push(c, line_index, column),
+            }
+        }
+    }
+    fields
+        .iter()
+        .filter(|field| !field.text.trim().is_empty())
+        .map(Field::parse)
+        .collect()
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        total(match &self.rule {
+            Some(rule) => sum_invalid_ids(input, rule),
+            None => day02_1::solve(input),
+        })
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        total(match &self.rule {
+            Some(rule) => sum_invalid_ids(input, rule),
+            None => day02_2::solve(input),
+        })
+    }
+}
+
+// sums beyond u64 are answered as text, as is a sum beyond u128
+fn total(sum: Option<u128>) -> Answer {
+    sum.map_or_else(
+        || Answer::Text("the sum of the invalid IDs overflows u128".to_string()),
+        Answer::from,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day02, IdRule, Pattern, parse, sum_invalid_ids};
+    use crate::advent_of_code::{Answer, Reader, Solution, Variant, lines};
+
+    const DAY02: Day02 = Day02 { rule: None };
+
+    fn brute_force(rule: &IdRule, lower: u64, upper: u64) -> (u128, u128) {
+        (lower..=upper)
+            .filter(|&n| rule.matches(n))
+            .fold((0, 0), |(count, sum), n| (count + 1, sum + n as u128))
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let intervals = [
+            (0, 200_000),
+            (998, 1012),
+            (1_188_511_880, 1_188_511_890),
+            (2_121_212_118, 2_121_212_124),
+            (123_123, 123_123),
+            (5, 4),
+        ];
+        let patterns = [
+            Pattern::Exactly(2),
+            Pattern::AtLeast(2),
+            Pattern::Exactly(3),
+            Pattern::AtLeast(4),
+            Pattern::Palindrome,
+        ];
+        for (lower, upper) in intervals {
+            for pattern in patterns {
+                for base in [10, 2, 7, 16] {
+                    let rule = IdRule::new(pattern).in_base(base);
+                    assert_eq!(
+                        rule.invalid_ids(lower, upper),
+                        brute_force(&rule, lower, upper),
+                        "{} in {}-{}",
+                        rule,
+                        lower,
+                        upper
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rules_by_example() {
+        let twice = IdRule::TWICE;
+        assert!(twice.matches(1212) && twice.matches(1111) && !twice.matches(121212));
+        assert!(IdRule::AT_LEAST_TWICE.matches(121212));
+        assert!(!IdRule::AT_LEAST_TWICE.matches(7));
+        let palindrome = IdRule::new(Pattern::Palindrome);
+        assert!(palindrome.matches(7) && palindrome.matches(12321) && !palindrome.matches(10));
+        // 0b101101 and 0xabab
+        assert!(twice.in_base(2).matches(45) && !twice.matches(45));
+        assert!(twice.in_base(16).matches(0xabab));
+    }
+
+    #[test]
+    fn ranges_too_large_to_enumerate() {
+        // 9 one-digit blocks repeated twice, 90 two-digit blocks, .. up to 6 digits
+        let (count, _) = IdRule::TWICE.invalid_ids(1, 10u64.pow(12));
+        assert_eq!(count, 999_999);
+        let (at_least_twice, _) = IdRule::AT_LEAST_TWICE.invalid_ids(1, 10u64.pow(12));
+        assert!(at_least_twice > count);
+        // 9 palindromes of 1 and of 2 digits, 90 of 3 and of 4 digits, .. up to 12 digits
+        let palindrome = IdRule::new(Pattern::Palindrome);
+        assert_eq!(palindrome.invalid_ids(1, 10u64.pow(12)).0, 1_999_998);
+        let (count, sum) = palindrome.in_base(3).invalid_ids(0, u64::MAX);
+        assert!(count > 3u128.pow(20) && sum > u64::MAX as u128);
+    }
+
+    #[test]
+    fn sums_beyond_u64() {
+        let input = parse(&lines(&["1-18446744073709551615"])).unwrap();
+        // the 844_674_408 IDs of 20 digits alone, from the halves 1_000_000_000 to 1_844_674_407,
+        // sum to more than 8 * 10^27
+        let Answer::Text(sum) = DAY02.part1(&input) else {
+            panic!("expected a sum beyond u64");
+        };
+        assert!(sum.parse::<u128>().unwrap() > 8 * 10u128.pow(27));
+        assert!(matches!(DAY02.part2(&input), Answer::Text(_)));
+        assert_eq!(
+            sum_invalid_ids(&[(1, 9), (11, 11)], &IdRule::TWICE),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn rule_names() {
+        for name in ["exactly:3", "at-least:2", "palindrome", "at-least:3@16"] {
+            assert_eq!(name.parse::<IdRule>().unwrap().to_string(), name);
+        }
+        assert_eq!(
+            "exactly:2@2".parse(),
+            Ok(IdRule::new(Pattern::Exactly(2)).in_base(2))
+        );
+        assert_eq!(
+            "exactly:1".parse::<IdRule>().unwrap_err(),
+            "invalid repeat count '1', expected 2 or more"
+        );
+        assert!("palindrome@1".parse::<IdRule>().is_err());
+        assert!("twice".parse::<IdRule>().is_err());
+        assert!("exactly".parse::<IdRule>().is_err());
+    }
+
+    #[test]
+    fn both_parts_on_the_example() {
+        let data = Reader::read_input(2, Variant::Example).unwrap();
+        let input = DAY02.parse(&data).unwrap();
+        assert_eq!(input.len(), 11);
+        assert_eq!(input[2], (998, 1012));
+        assert_eq!(DAY02.part1(&input), 1227775554u64.into());
+        assert_eq!(DAY02.part2(&input), 4174379265u64.into());
+    }
+
+    #[test]
+    fn both_parts_on_small_intervals() {
+        let input = parse(&lines(&["11-22,95-115,111-111"])).unwrap();
+        // 11, 22 and 99 are made of two halves, 111 only of three ones
+        assert_eq!(DAY02.part1(&input), (11u64 + 22 + 99).into());
+        assert_eq!(DAY02.part2(&input), (11u64 + 22 + 99 + 111 + 111).into());
+    }
+
+    #[test]
+    fn parse_wrapped_lists() {
+        let expected = [(11, 22), (95, 115), (998, 1012)];
+        assert_eq!(parse(&lines(&["11-22,95-115,998-1012"])).unwrap(), expected);
+        assert_eq!(
+            parse(&lines(&["11-22,95-1", "15,998-", "1012,", "", ""])).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse(&lines(&["11-22,", " 95-115 ,998-1012"])).unwrap(),
+            expected
+        );
+        assert!(parse(&lines(&["", ""])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_reports_malformed_intervals() {
+        let error = |data: &[&str]| parse(&lines(data)).unwrap_err().to_string();
+        assert_eq!(
+            error(&["11-22,95"]),
+            "line 1, column 7: expected an interval like '11-22', found '95'"
+        );
+        assert_eq!(
+            error(&["11-22,", "1-2-3"]),
+            "line 2, column 1: expected an interval like '11-22', found '1-2-3'"
+        );
+        assert_eq!(
+            error(&["11-22, 95-1x5"]),
+            "line 1, column 11: cannot parse '1x5': invalid digit found in string"
+        );
+        assert_eq!(
+            error(&["11-22,95-", "1 5"]),
+            "line 2, column 1: cannot parse '1 5': invalid digit found in string"
+        );
+        assert_eq!(
+            error(&["22-11"]),
+            "line 1, column 1: interval '22-11' ends before it starts"
+        );
+    }
+
+    #[test]
+    fn rule_replaces_both_parts() {
+        let input = parse(&lines(&["1-30,95-115"])).unwrap();
+        let day = Day02 {
+            rule: Some(IdRule::new(Pattern::Palindrome)),
+        };
+        let palindromes = (1..=9).sum::<u64>() + 11 + 22 + 99 + 101 + 111;
+        assert_eq!(day.part1(&input), palindromes.into());
+        assert_eq!(day.part2(&input), palindromes.into());
+    }
+}
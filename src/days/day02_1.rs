@@ -0,0 +1,7 @@
+use super::day02::{IdRule, sum_invalid_ids};
+
+// sums all invalid IDs found in the intervals, made from two identical chunks, e.g., 1212,
+// 3333, 4545, but not 1234 or 123123 or 12341234
+pub fn solve(intervals: &[(u64, u64)]) -> Option<u128> {
+    sum_invalid_ids(intervals, &IdRule::TWICE)
+}
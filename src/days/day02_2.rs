@@ -0,0 +1,7 @@
+use super::day02::{IdRule, sum_invalid_ids};
+
+// sums all invalid IDs found in the intervals, made from two or more identical chunks, e.g.,
+// 1212, 123123 or 1111111
+pub fn solve(intervals: &[(u64, u64)]) -> Option<u128> {
+    sum_invalid_ids(intervals, &IdRule::AT_LEAST_TWICE)
+}
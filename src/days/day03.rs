@@ -0,0 +1,52 @@
+use crate::advent_of_code::{Answer, InputError, Solution, expect_chars};
+
+use super::{day03_1, day03_2};
+
+pub struct Day03;
+
+/// Number of batteries turned on in every bank by part 2.
+pub const BATTERIES_ON: usize = 12;
+
+/// Parses one bank of batteries per line, one joltage digit each.
+pub fn parse(data: &[String]) -> Result<Vec<Vec<u32>>, InputError> {
+    expect_chars(data, "0123456789")?;
+    Ok(data
+        .iter()
+        .map(|line| line.chars().filter_map(|c| c.to_digit(10)).collect())
+        .collect())
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day03_1::puzzle(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day03_2::puzzle(input).map_or_else(Answer::Text, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day03, parse};
+    use crate::advent_of_code::lines;
+    use crate::advent_of_code::{Answer, Solution};
+
+    #[test]
+    fn short_banks_only_fail_part_2() {
+        let data = lines(&["987654321111111", "12345"]);
+        let input = parse(&data).unwrap();
+        assert_eq!(Day03.part1(&input), (98u32 + 45).into());
+        assert_eq!(
+            Day03.part2(&input),
+            Answer::Text("bank 2 has 5 batteries, part 2 turns on 12".to_string())
+        );
+    }
+}
@@ -0,0 +1,35 @@
+fn find_largest_pair(digits: &[u32]) -> u32 {
+    // Try digits 9 down to 0 as first digit
+    for first_digit in (0..=9).rev() {
+        if let Some(first_pos) = digits.iter().position(|&d| d == first_digit) {
+            // Simply find the max in the remaining slice - no iteration needed!
+            // Note: In case the highest number is in the last position(s), this will still work correctly.
+            // Because the `Some` will fail because the iter().max() will return None --> empty slice.
+            if let Some(&max_second) = digits[(first_pos + 1)..].iter().max() {
+                return first_digit * 10 + max_second;
+            }
+        }
+    }
+    0
+}
+
+// The puzlle calls for two lists (given as two columns in a ascii file) to be sorted and line by line the absolute differences need to be summed up.
+pub fn puzzle(banks: &[Vec<u32>]) -> u32 {
+    banks
+        .iter()
+        .map(|bank| find_largest_pair(bank))
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_largest_pair;
+    use crate::days::day03::parse;
+
+    #[test]
+    fn test_find_largest_pair() {
+        let banks = parse(&["234239423423427".to_string()]).unwrap();
+        let result = find_largest_pair(&banks[0]);
+        assert_eq!(result, 97);
+    }
+}
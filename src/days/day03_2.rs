@@ -0,0 +1,109 @@
+use super::day03::BATTERIES_ON;
+
+/// Find the largest digit that appears earliest in the slice
+fn find_largest_digit_greedy(digits: &[u32]) -> Option<(u32, usize)> {
+    // Try digits 9 down to 0 as first digit, a window of zeros still has a largest digit
+    for first_digit in (0..=9).rev() {
+        if let Some(first_pos) = digits.iter().position(|&d| d == first_digit) {
+            return Some((first_digit, first_pos));
+        }
+    }
+    None
+}
+// largest possible joltage, Will be EXCACTLY 12 digits long, `None` if there are not enough digits
+fn find_largest_joltage(digits: &[u32]) -> Option<u64> {
+    let mut left: usize = 0;
+    let mut right: usize = digits.len().checked_sub(BATTERIES_ON - 1)?; // start with all but the last 11 digits
+    let mut joltage: u64 = 0;
+    loop {
+        let d1 = find_largest_digit_greedy(&digits[left..right])?;
+        left += d1.1 + 1;
+        right += 1;
+        joltage *= 10;
+        joltage += d1.0 as u64;
+        if right > digits.len() {
+            break;
+        }
+    }
+    Some(joltage)
+}
+
+// the copilot solution, I didn't know about `scan` at that time. It's elegant, but maybe less readable?
+#[allow(dead_code)]
+fn find_largest_joltage_elegant(digits: &[u32]) -> u64 {
+    // Use iterator-based approach with scan to maintain state
+    let window_size = digits.len().saturating_sub(BATTERIES_ON - 1);
+    (0..BATTERIES_ON)
+        .scan((0, window_size), |(left, right), _| {
+            if *right > digits.len() {
+                return None;
+            }
+
+            let digit_info = find_largest_digit_greedy(&digits[*left..*right])?;
+            *left += digit_info.1 + 1;
+            *right += 1;
+
+            Some(digit_info.0 as u64)
+        })
+        .fold(0, |acc, digit| acc * 10 + digit)
+}
+
+// the error names the first bank with fewer batteries than part 2 turns on
+pub fn puzzle(banks: &[Vec<u32>]) -> Result<u64, String> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(index, bank)| {
+            find_largest_joltage(bank).ok_or_else(|| {
+                format!(
+                    "bank {} has {} batteries, part 2 turns on {}",
+                    index + 1,
+                    bank.len(),
+                    BATTERIES_ON
+                )
+            })
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day03::parse;
+
+    fn digits(s: &str) -> Vec<u32> {
+        parse(&[s.to_string()]).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_find_largest_joltage() {
+        let result = super::find_largest_joltage_elegant(&digits("234234234234278"));
+        assert_eq!(result, 434234234278);
+        let result = super::find_largest_joltage_elegant(&digits("818181911112111"));
+        assert_eq!(result, 888911112111);
+        let result = super::find_largest_joltage_elegant(&digits("811111111111119"));
+        assert_eq!(result, 811111111119);
+        let result = super::find_largest_joltage_elegant(&digits("818181911112111"));
+        assert_eq!(result, 888911112111);
+    }
+
+    #[test]
+    fn banks_with_zeros() {
+        assert_eq!(
+            super::find_largest_joltage(&digits("100000000000009")),
+            Some(100000000009)
+        );
+        assert_eq!(
+            super::find_largest_joltage(&digits("000000000000")),
+            Some(0)
+        );
+        assert_eq!(super::find_largest_joltage(&digits("12345")), None);
+        assert_eq!(super::puzzle(&[digits("0000000000001")]), Ok(1));
+    }
+
+    #[test]
+    fn test_find_largest_digit_greedy() {
+        let digits = vec![2, 3, 4, 2];
+        let result = super::find_largest_digit_greedy(&digits);
+        assert_eq!(result, Some((4, 2)));
+    }
+}
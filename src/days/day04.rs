@@ -0,0 +1,41 @@
+use crate::advent_of_code::{Answer, Grid, InputError, Solution};
+
+use super::{day04_1, day04_2};
+
+pub struct Day04;
+
+/// A position of the grid, either empty or holding a roll of paper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Roll,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Cell::Empty),
+            '@' => Ok(Cell::Roll),
+            _ => Err("expected '.' or '@'"),
+        }
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Grid<Cell>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        Grid::parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day04_1::solve(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day04_2::solve(input).into()
+    }
+}
@@ -0,0 +1,25 @@
+use super::day04::Cell;
+use crate::advent_of_code::{Grid, Neighbourhood, Point};
+use crate::debug;
+
+fn is_accessible(grid: &Grid<Cell>, row: usize, col: usize) -> bool {
+    let at_count = grid
+        .neighbour_cells(Point::new(row, col), Neighbourhood::Moore)
+        .filter(|&(_, &c)| c == Cell::Roll)
+        .count();
+    at_count < 4
+}
+
+pub fn solve(grid: &Grid<Cell>) -> u32 {
+    // find all '@' positions
+    let at_positions = grid.find_char_positions(Cell::Roll);
+    debug!("Found <{}> '@'", at_positions.len());
+    // filter positions directly
+    let accessible_count = at_positions
+        .iter()
+        .filter(|&&(row, col)| is_accessible(grid, row, col))
+        .count();
+
+    debug!("Accessible '@' count: {}", accessible_count);
+    accessible_count as u32
+}
@@ -0,0 +1,30 @@
+use super::day04::Cell;
+use crate::advent_of_code::{BitGrid, Grid};
+use crate::{debug, trace};
+
+/// Removes accessible rolls generation by generation until none is left accessible.
+///
+/// A roll is accessible if fewer than 4 of its 8 neighbours are rolls, all accessible rolls of a
+/// generation are removed at once and counted.
+pub fn solve(grid: &Grid<Cell>) -> u32 {
+    let mut rolls = BitGrid::from_grid(grid, |&c| c == Cell::Roll);
+    debug!("Found <{}> '@'", rolls.count_ones());
+    let mut accessible_count = 0;
+
+    loop {
+        let accessible = rolls.and(&rolls.neighbours_fewer_than(4));
+        let count = accessible.count_ones();
+        trace!("Found <{}> accessible '@'", count);
+
+        // If no positions are accessible, we're done
+        if count == 0 {
+            break;
+        }
+
+        accessible_count += count;
+        rolls = rolls.and_not(&accessible);
+    }
+
+    debug!("Remaining '@' count: {}", rolls.count_ones());
+    accessible_count as u32
+}
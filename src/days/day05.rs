@@ -0,0 +1,22 @@
+use crate::advent_of_code::{Answer, InputError, Solution};
+
+use super::{day05_1, day05_2};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = day05_1::Inventory;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        day05_1::parse(data)
+    }
+
+    fn part1(&self, (ranges, ingredients): &Self::Input) -> Answer {
+        day05_1::solve(ranges, ingredients).into()
+    }
+
+    fn part2(&self, (ranges, _): &Self::Input) -> Answer {
+        day05_2::solve(ranges).into()
+    }
+}
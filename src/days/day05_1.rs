@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::advent_of_code::{InputError, parse_field};
+
+// fresh ingredient ranges and the available ingredients
+pub type Inventory = (Vec<(u64, u64)>, HashSet<u64>);
+
+// `index` is the line index of `s`, for error reporting
+fn parse_u64_pair(s: &str, delimiter: char, index: usize) -> Result<(u64, u64), InputError> {
+    // Attempt to split the string once by the given delimiter.
+    let (first_str, second_str) = s.split_once(delimiter).ok_or_else(|| {
+        InputError::parse(
+            index,
+            0,
+            format!("expected two numbers separated by '{}'", delimiter),
+        )
+    })?;
+    // Attempt to parse the first part into a u64.
+    let first_num = parse_field(s, first_str, index)?;
+    // Attempt to parse the second part into a u64.
+    let second_num = parse_field(s, second_str, index)?;
+    if first_num > second_num {
+        return Err(InputError::parse(
+            index,
+            0,
+            format!("range '{}' ends before it starts", s),
+        ));
+    }
+    // If both parsing operations succeed, return the tuple.
+    Ok((first_num, second_num))
+}
+
+fn is_fresh(ingredient: &u64, ranges: &[(u64, u64)]) -> bool {
+    ranges
+        .iter()
+        .any(|(min, max)| ingredient >= min && ingredient <= max)
+}
+
+// ranges and ingredients are separated by an empty line
+pub fn parse(data: &[String]) -> Result<Inventory, InputError> {
+    let mut lines = data.iter().enumerate();
+    // read ranges until empty line
+    let ranges = lines
+        .by_ref()
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_u64_pair(line, '-', index))
+        .collect::<Result<Vec<(u64, u64)>, InputError>>()?;
+    // read ingredients from remaining lines, ignoring trailing empty lines
+    let ingredients = lines
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_field(line, line, index))
+        .collect::<Result<HashSet<u64>, InputError>>()?;
+    Ok((ranges, ingredients))
+}
+
+pub fn solve(ranges: &[(u64, u64)], ingredients: &HashSet<u64>) -> usize {
+    ingredients
+        .iter()
+        .filter(|&ingredient| is_fresh(ingredient, ranges))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::advent_of_code::lines;
+
+    #[test]
+    fn parse_reports_bad_range() {
+        let d = lines(&["3-5", "10 14"]);
+        let err = parse(&d).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected two numbers separated by '-'"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_reversed_range() {
+        let d = lines(&["3-5", "10-3"]);
+        assert_eq!(
+            parse(&d).unwrap_err().to_string(),
+            "line 2, column 1: range '10-3' ends before it starts"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::trace;
+
+// Major EBCAK, I need more memory, or have to grow a brain...
+// This takes TB of memory for large ranges...
+// fn pair_insert_into_HashSet(pair: (u64, u64), set: &mut HashSet<u64>) {
+//     for num in pair.0..=pair.1 {
+//         set.insert(num);
+//     }
+// }
+
+fn merge_ranges_into_vec(pairs: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    // initialize with first range, if there is any
+    let Some((&first, rest)) = pairs.split_first() else {
+        return Vec::new();
+    };
+    let mut merged: Vec<(u64, u64)> = vec![first];
+    for &(start, end) in rest.iter() {
+        let last = merged.last_mut().unwrap();
+        if start <= last.1.saturating_add(1) {
+            // Ranges overlap or are contiguous, merge them
+            last.1 = last.1.max(end); // max to deal containing ranges
+        } else {
+            // No overlap, add new range
+            merged.push((start, end));
+        }
+    }
+    merged
+}
+
+// the count is a u128, all of u64 is one more than u64::MAX
+pub fn solve(ranges: &[(u64, u64)]) -> u128 {
+    let mut ranges = ranges.to_vec();
+    ranges.sort();
+    let merged_ranges = merge_ranges_into_vec(&ranges);
+    trace!("merged ranges: {:?}", merged_ranges);
+    merged_ranges
+        .iter()
+        .map(|(start, end)| (end - start) as u128 + 1)
+        .sum::<u128>()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn merge_ranges_into_vec_test() {
+        let mut input = vec![(5, 10), (1, 3), (2, 6), (15, 20), (18, 22), (19, 20)];
+        input.sort();
+        let expected = vec![(1, 10), (15, 22)];
+        let result = super::merge_ranges_into_vec(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn no_ranges() {
+        assert!(super::merge_ranges_into_vec(&[]).is_empty());
+        let inventory = crate::days::day05_1::parse(&[String::new()]).unwrap();
+        assert_eq!(super::solve(&inventory.0), 0);
+    }
+
+    #[test]
+    fn ranges_up_to_u64_max() {
+        let max = u64::MAX;
+        let input = [(0, 5), (max - 1, max), (max, max)];
+        assert_eq!(
+            super::merge_ranges_into_vec(&input),
+            [(0, 5), (max - 1, max)]
+        );
+        assert_eq!(super::solve(&input), 8);
+        assert_eq!(super::solve(&[(0, max)]), max as u128 + 1);
+    }
+}
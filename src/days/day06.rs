@@ -0,0 +1,240 @@
+use crate::advent_of_code::{Answer, Grid, InputError, Point, Ragged, Solution, expect_chars};
+
+use super::{day06_1, day06_2};
+
+pub struct Day06;
+
+// a number of more digits might not fit into u64
+const MAX_DIGITS: usize = 19;
+
+/// How the numbers of a problem are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    pub fn apply<I>(self, operands: I) -> u64
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        match self {
+            Operation::Add => operands.into_iter().sum(),
+            Operation::Multiply => operands.into_iter().product(),
+        }
+    }
+}
+
+/// One problem of the worksheet, the digits above its operation.
+///
+/// The block has a number in every row and a digit in every column, rows are padded with spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub operation: Operation,
+    pub block: Grid<char>,
+}
+
+/// Parses the rows of numbers and the line of operations below them into problems.
+///
+/// Problems are separated by columns of spaces, the operation of a problem can be anywhere
+/// below it.
+pub fn parse(data: &[String]) -> Result<Vec<Problem>, InputError> {
+    // rows of numbers, followed by a line of operations
+    let Some((operations, numbers)) = data.split_last() else {
+        return Err(InputError::parse(0, 0, "missing the line of operations"));
+    };
+    if numbers.is_empty() {
+        return Err(InputError::parse(0, 0, "missing the rows of numbers"));
+    }
+    if numbers.len() > MAX_DIGITS {
+        return Err(InputError::parse(
+            MAX_DIGITS,
+            0,
+            format!("more than {} rows of numbers", MAX_DIGITS),
+        ));
+    }
+    expect_chars(numbers, "0123456789 ")?;
+    let operations_row = numbers.len();
+    if let Some(column) = operations.find(|c| !"+* ".contains(c)) {
+        return Err(InputError::parse(
+            operations_row,
+            column,
+            "expected only '+' and '*' operations",
+        ));
+    }
+
+    // trailing spaces may be cut from some lines, so pad them to a rectangle
+    let rows = numbers.iter().map(|line| line.chars().collect()).collect();
+    let sheet = Grid::from_rows(rows, Ragged::Pad(' '))?;
+    let operations: Vec<char> = operations.chars().collect();
+    let is_separator = |col: usize| sheet.column(col).all(|&c| c == ' ');
+
+    let mut problems = Vec::new();
+    let mut col = 0;
+    while col < sheet.cols() {
+        if is_separator(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < sheet.cols() && !is_separator(col) {
+            col += 1;
+        }
+        let block = sheet
+            .crop(Point::new(0, start), sheet.rows(), col - start)
+            .unwrap();
+        for (row, line) in block.iter_rows().enumerate() {
+            check_number(line, row, start)?;
+        }
+        let below = operations
+            .get(start..col.min(operations.len()))
+            .unwrap_or(&[]);
+        let mut found = below.iter().enumerate().filter(|(_, c)| **c != ' ');
+        let operation = match (found.next(), found.next()) {
+            (Some((_, '+')), None) => Operation::Add,
+            (Some(_), None) => Operation::Multiply,
+            (None, _) => {
+                return Err(InputError::parse(
+                    operations_row,
+                    start,
+                    "missing the operation of the problem above",
+                ));
+            }
+            (Some(_), Some((column, _))) => {
+                return Err(InputError::parse(
+                    operations_row,
+                    start + column,
+                    "more than one operation for the problem above",
+                ));
+            }
+        };
+        problems.push(Problem { operation, block });
+    }
+
+    // operations below separators or past the numbers belong to no problem
+    if let Some(column) = (0..operations.len())
+        .find(|&col| operations[col] != ' ' && (col >= sheet.cols() || is_separator(col)))
+    {
+        return Err(InputError::parse(
+            operations_row,
+            column,
+            "operation without numbers above it",
+        ));
+    }
+    Ok(problems)
+}
+
+// a row of a problem holds one number, `start` is the column of the problem
+fn check_number(line: &[char], row: usize, start: usize) -> Result<(), InputError> {
+    let Some(first) = line.iter().position(|&c| c != ' ') else {
+        return Err(InputError::parse(
+            row,
+            start,
+            "missing a number of the problem",
+        ));
+    };
+    let len = line[first..]
+        .iter()
+        .position(|&c| c == ' ')
+        .unwrap_or(line.len() - first);
+    if let Some(gap) = line[first + len..].iter().position(|&c| c != ' ') {
+        return Err(InputError::parse(
+            row,
+            start + first + len + gap,
+            "more than one number in a row of the problem",
+        ));
+    }
+    if len > MAX_DIGITS {
+        return Err(InputError::parse(
+            row,
+            start + first,
+            format!("number has more than {} digits", MAX_DIGITS),
+        ));
+    }
+    Ok(())
+}
+
+impl Problem {
+    /// The numbers read row by row, as written.
+    pub fn rows(&self) -> impl Iterator<Item = u64> + '_ {
+        self.block.iter_rows().map(|row| number(row.iter()))
+    }
+
+    /// The columns of digits, top to bottom, with the spaces of shorter numbers.
+    pub fn columns(&self) -> impl Iterator<Item = Vec<char>> + '_ {
+        self.block
+            .iter_columns()
+            .map(|column| column.copied().collect())
+    }
+}
+
+// the digits among `chars`, skipping spaces
+fn number<'a>(chars: impl Iterator<Item = &'a char>) -> u64 {
+    chars
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |acc, d| acc * 10 + d as u64)
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Vec<Problem>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day06_1::puzzle(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day06_2::puzzle(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Operation, parse};
+    use crate::advent_of_code::lines;
+
+    #[test]
+    fn parse_problems() {
+        let problems = parse(&lines(&["123 328", " 45 64", "*   +  "])).unwrap();
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].operation, Operation::Multiply);
+        assert_eq!(problems[0].rows().collect::<Vec<_>>(), [123, 45]);
+        assert_eq!(problems[1].operation, Operation::Add);
+        assert_eq!(problems[1].rows().collect::<Vec<_>>(), [328, 64]);
+        assert_eq!(problems[1].columns().next(), Some(vec!['3', '6']));
+    }
+
+    #[test]
+    fn parse_reports_malformed_problems() {
+        let error = |data: &[&str]| parse(&lines(data)).unwrap_err().to_string();
+        assert_eq!(
+            error(&["123 328", " 45    ", "*   +"]),
+            "line 2, column 5: missing a number of the problem"
+        );
+        assert_eq!(
+            error(&["1 3 328", "12345 6", "*   +"]),
+            "line 1, column 3: more than one number in a row of the problem"
+        );
+        assert_eq!(
+            error(&["123 328", " 45 64", "*    "]),
+            "line 3, column 5: missing the operation of the problem above"
+        );
+        assert_eq!(
+            error(&["123 328", " 45 64", "*+  +"]),
+            "line 3, column 2: more than one operation for the problem above"
+        );
+        assert_eq!(
+            error(&["123 328", " 45 64", "*  ++"]),
+            "line 3, column 4: operation without numbers above it"
+        );
+        assert_eq!(
+            error(&["*"]),
+            "line 1, column 1: missing the rows of numbers"
+        );
+    }
+}
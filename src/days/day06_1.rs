@@ -0,0 +1,9 @@
+use super::day06::Problem;
+
+// every problem reads its numbers row by row
+pub fn puzzle(problems: &[Problem]) -> u64 {
+    problems
+        .iter()
+        .map(|problem| problem.operation.apply(problem.rows()))
+        .sum::<u64>()
+}
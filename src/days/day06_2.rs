@@ -0,0 +1,52 @@
+use super::day06::Problem;
+use crate::trace;
+
+fn char_vec_to_int(chars: &[char]) -> Option<u64> {
+    if chars.iter().all(|c| *c == ' ') {
+        return None;
+    }
+    let value = chars
+        .iter()
+        .filter_map(|c| c.to_digit(10).map(|d| d as u64))
+        .fold(0, |acc, d| acc * 10 + d);
+    Some(value)
+}
+
+// cephalopod math: every column of a problem is one number, read top to bottom
+pub fn puzzle(problems: &[Problem]) -> u64 {
+    problems
+        .iter()
+        .map(|problem| {
+            let numbers: Vec<u64> = problem
+                .columns()
+                .inspect(|chars| trace!("chars: {:?}", chars))
+                .filter_map(|chars| char_vec_to_int(&chars))
+                .collect();
+            problem.operation.apply(numbers)
+        })
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn char_vec_to_int_test_trailing_spaces() {
+        let chars = vec!['1', '2', '3', ' '];
+        let result = super::char_vec_to_int(&chars);
+        assert_eq!(result, Some(123));
+    }
+
+    #[test]
+    fn char_vec_to_int_test_leading_spaces() {
+        let chars = vec![' ', '1', '2', '3'];
+        let result = super::char_vec_to_int(&chars);
+        assert_eq!(result, Some(123));
+    }
+
+    #[test]
+    fn char_vec_to_int_test_all_spaces() {
+        let chars = vec![' ', ' ', ' ', ' '];
+        let result = super::char_vec_to_int(&chars);
+        assert_eq!(result, None);
+    }
+}
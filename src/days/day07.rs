@@ -0,0 +1,75 @@
+use crate::advent_of_code::{Answer, InputError, Solution, expect_chars};
+
+use super::{day07_1, day07_2};
+
+pub struct Day07;
+
+/// The tachyon manifold: where the beam enters and the splitters it falls through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifold {
+    /// Column of the 'S' in the first line.
+    pub start: usize,
+    /// Columns of the splitters of every line that has any, from top to bottom, in ascending order.
+    pub splitters: Vec<Vec<usize>>,
+}
+
+/// Parses the diagram of the manifold, splitters '^' may not be in the first or last column.
+pub fn parse(data: &[String]) -> Result<Manifold, InputError> {
+    expect_chars(data, ".S^")?;
+    let Some(start) = data.first().and_then(|line| line.find('S')) else {
+        return Err(InputError::parse(0, 0, "the first line has no start 'S'"));
+    };
+    let mut splitters = Vec::new();
+    for (index, line) in data.iter().enumerate() {
+        let columns: Vec<usize> = line.match_indices('^').map(|(col, _)| col).collect();
+        // a split beam has to stay inside the manifold
+        if let Some(&col) = columns
+            .iter()
+            .find(|&&col| col == 0 || col + 1 == line.len())
+        {
+            return Err(InputError::parse(
+                index,
+                col,
+                "splitter at the edge of the manifold",
+            ));
+        }
+        if !columns.is_empty() {
+            splitters.push(columns);
+        }
+    }
+    Ok(Manifold { start, splitters })
+}
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Manifold;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day07_1::puzzle(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day07_2::puzzle(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::advent_of_code::lines;
+
+    #[test]
+    fn parse_manifold() {
+        let manifold = parse(&lines(&["..S..", ".....", ".^.^.", "..^.."])).unwrap();
+        assert_eq!(manifold.start, 2);
+        assert_eq!(manifold.splitters, [vec![1, 3], vec![2]]);
+        assert_eq!(
+            parse(&lines(&["..S..", "....^"])).unwrap_err().to_string(),
+            "line 2, column 5: splitter at the edge of the manifold"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use super::day07::Manifold;
+use crate::{debug, trace};
+
+pub fn puzzle(manifold: &Manifold) -> u32 {
+    let mut positions: HashSet<usize> = HashSet::new();
+    positions.insert(manifold.start);
+    debug!("start positions: {:?}", positions);
+    trace!("all splitter positions: {:?}", manifold.splitters);
+
+    let mut total_splits: u32 = 0;
+    for splitter in &manifold.splitters {
+        for s in splitter.iter() {
+            if positions.remove(s) {
+                total_splits += 1;
+                positions.insert(s - 1);
+                positions.insert(s + 1);
+            }
+        }
+    }
+
+    total_splits
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use super::day07::Manifold;
+use crate::trace;
+
+// this puzzle is like those pin pyramids where a ball falls down and splits at each fork
+pub fn puzzle(manifold: &Manifold) -> usize {
+    trace!("all splitter positions: {:?}", manifold.splitters);
+
+    // each timeline is a unique path from start to end, but it's enough to count how many timelines reach each position
+    let mut timelines: HashMap<usize, usize> = HashMap::new();
+    let start_pos = manifold.start;
+    // initialize with starting position
+    timelines.insert(start_pos, 1); // we will put the positions in the HashSet when a splitter is encountered
+    trace!("start timelines: {:?}", timelines);
+
+    // when a splitter is encountered, each timeline splits into two new timelines
+    for splitter in &manifold.splitters {
+        // all splitters in that line
+        for s in splitter.iter() {
+            // each splitter position
+            if let Some(timelines_at_s) = timelines.remove(s) {
+                // remove the tachion at that position
+                // create two new timelines at s+1 and s-1 (increase counter if already exists)
+                timelines
+                    .entry(s + 1)
+                    .and_modify(|counter| *counter += timelines_at_s)
+                    .or_insert(timelines_at_s);
+                timelines
+                    .entry(s - 1)
+                    .and_modify(|counter| *counter += timelines_at_s)
+                    .or_insert(timelines_at_s);
+            }
+        }
+    }
+    timelines.values().copied().sum()
+    // 0usize
+}
@@ -0,0 +1,21 @@
+use crate::advent_of_code::{Answer, InputError, Solution};
+
+use super::day08_1;
+
+pub struct Day08 {
+    // number of closest pairs to connect, 10 for the example and 1000 for the real input
+    pub connections: usize,
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Vec<day08_1::JunctionBox>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        day08_1::parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day08_1::solve(input, self.connections).into()
+    }
+}
@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, vec};
 
-#[path = "../advent_of_code/mod.rs"]
-mod advent_of_code;
+use crate::advent_of_code::{InputError, parse_field};
+use crate::{debug, trace};
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 struct Circuit {
@@ -35,8 +35,8 @@ impl Circuit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
-struct JunctionBox {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JunctionBox {
     x: u32,
     y: u32,
     z: u32,
@@ -49,17 +49,31 @@ impl Ord for JunctionBox {
     }
 }
 
+impl PartialOrd for JunctionBox {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl JunctionBox {
     fn new(x: u32, y: u32, z: u32) -> Self {
         JunctionBox { x, y, z }
     }
 
-    fn from_str(s: &str) -> Self {
+    // example str: "162,817,812", `index` is the line index for error reporting
+    fn from_str(s: &str, index: usize) -> Result<Self, InputError> {
         let parts: Vec<u32> = s
             .split(',')
-            .map(|part| part.trim().parse::<u32>().unwrap())
-            .collect();
-        JunctionBox::new(parts[0], parts[1], parts[2])
+            .map(|part| parse_field(s, part.trim(), index))
+            .collect::<Result<_, _>>()?;
+        match parts[..] {
+            [x, y, z] => Ok(JunctionBox::new(x, y, z)),
+            _ => Err(InputError::parse(
+                index,
+                0,
+                format!("expected 3 coordinates, found {}", parts.len()),
+            )),
+        }
     }
 
     fn distance(&self, other: &JunctionBox) -> u64 {
@@ -72,7 +86,7 @@ impl JunctionBox {
 }
 
 // brute force all possible pairs and scram them into a BTreeMap, which is sorted by distance
-fn closest_pair_map(boxes: &Vec<JunctionBox>) -> BTreeMap<u64, (&JunctionBox, &JunctionBox)> {
+fn closest_pair_map(boxes: &[JunctionBox]) -> BTreeMap<u64, (&JunctionBox, &JunctionBox)> {
     let mut box_map: BTreeMap<u64, (&JunctionBox, &JunctionBox)> = BTreeMap::new();
 
     for i in 0..boxes.len() {
@@ -84,20 +98,21 @@ fn closest_pair_map(boxes: &Vec<JunctionBox>) -> BTreeMap<u64, (&JunctionBox, &J
             box_map.insert(dist, (&boxes[i], &boxes[j]));
         }
     }
-    println!("......... created a map of {} pairs", box_map.len());
+    debug!("......... created a map of {} pairs", box_map.len());
     box_map
 }
 
-fn puzzle(data: &Vec<String>, take_n: usize) -> usize {
-    let boxes: Vec<JunctionBox> = data
-        .iter()
-        .map(|line| JunctionBox::from_str(line))
-        .collect();
-    // boxes.sort();
-    // boxes.iter().for_each(|b| println!("{:?}", b.show()));
-    println!("found {} junction boxes", data.len());
+pub fn parse(data: &[String]) -> Result<Vec<JunctionBox>, InputError> {
+    data.iter()
+        .enumerate()
+        .map(|(index, line)| JunctionBox::from_str(line, index))
+        .collect()
+}
+
+pub fn solve(boxes: &[JunctionBox], take_n: usize) -> usize {
+    debug!("found {} junction boxes", boxes.len());
 
-    let box_map = closest_pair_map(&boxes);
+    let box_map = closest_pair_map(boxes);
 
     let mut circuits: BTreeMap<u64, Circuit> = BTreeMap::new();
     // let (d, first_pair) = box_map.iter().next().unwrap();
@@ -107,16 +122,12 @@ fn puzzle(data: &Vec<String>, take_n: usize) -> usize {
     for b in box_map.iter().take(take_n + 1) {
         let pair = b.1;
         let mut found_match = false;
-        // println!("\n ==> Processing pair: {:?} with distance {}", pair, b.0);
+        trace!("processing pair {:?} with distance {}", pair, b.0);
         for c in circuits.iter_mut() {
-            // println!("checking against circuit with boxes {:?}", c.boxes);
             if c.1.contains_pair(*pair) {
                 c.1.insert_pair(*pair);
                 found_match = true;
-                // println!(
-                //     "+++> pair {:?} matches circuit with boxes {:?}",
-                //     pair, c.1.boxes
-                // );
+                trace!("pair {:?} matches circuit with boxes {:?}", pair, c.1.boxes);
                 break;
             }
         }
@@ -124,13 +135,13 @@ fn puzzle(data: &Vec<String>, take_n: usize) -> usize {
         if !found_match {
             let c = Circuit::from_pair(*pair);
             circuits.insert(*b.0, c);
-            // println!("created new circuit from pair: {:?}", pair);
+            trace!("created new circuit from pair {:?}", pair);
         }
 
         // circuits.extend(new_circuits);
     }
 
-    println!("\n\n----------------- Final Circuits -----------------\n\n");
+    debug!("----------------- Final Circuits -----------------");
     // circuits.sort();
     // circuits.reverse();
     let mut circuit_len: Vec<usize> = circuits.iter().map(|c| c.1.boxes.len()).collect();
@@ -139,36 +150,24 @@ fn puzzle(data: &Vec<String>, take_n: usize) -> usize {
     circuit_len
         .iter()
         .take(3)
-        .inspect(|x| println!("took: {}", x))
-        .fold(1 as usize, |acc, &x| acc * x)
-    // circuits.sort();
-    // println!("final circuits: {:?}", circuits);
-    // 0
-}
-
-fn main() {
-    let data = advent_of_code::Reader::read_file("./input/day08_test.txt").unwrap();
-    let result = puzzle(&data, 10);
-    println!("result: {}", result);
+        .inspect(|x| debug!("took: {}", x))
+        .product()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{advent_of_code, puzzle};
-
-    #[test]
-    fn puzzle_test_data() {
-        let d = advent_of_code::Reader::read_file("./input/day08_test.txt").unwrap();
-        let result = puzzle(&d, 10);
-        println!("result: {result}");
-        assert_eq!(result, 40);
-    }
+    use super::parse;
+    use crate::advent_of_code::lines;
 
     #[test]
-    fn puzzle_final_data() {
-        let d = advent_of_code::Reader::read_file("./input/day08.txt").unwrap();
-        let result = puzzle(&d, 1000);
-        println!("result: {result}");
-        assert_eq!(result, 0);
+    fn parse_reports_bad_coordinate() {
+        let d = lines(&["162,817,812", "57,61a,883"]);
+        let err = parse(&d).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: cannot parse '61a': invalid digit found in string"
+        );
+        let d = lines(&["162,817"]);
+        assert!(parse(&d).is_err());
     }
 }
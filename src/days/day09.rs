@@ -0,0 +1,51 @@
+use crate::advent_of_code::{Answer, InputError, Solution, parse_field};
+
+use super::{day09_1, day09_2};
+
+pub struct Day09;
+
+/// A red tile of the floor.
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    pub fn new(x: u32, y: u32) -> Self {
+        Tile { x, y }
+    }
+}
+
+/// Parses one red tile "x,y" per line.
+pub fn parse(data: &[String]) -> Result<Vec<Tile>, InputError> {
+    data.iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let Some((x, y)) = line.split_once(',') else {
+                return Err(InputError::parse(index, 0, "expected a tile 'x,y'"));
+            };
+            Ok(Tile::new(
+                parse_field(line, x, index)?,
+                parse_field(line, y, index)?,
+            ))
+        })
+        .collect()
+}
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Tile>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day09_1::puzzle(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day09_2::puzzle(input).into()
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::BTreeSet;
+
+use super::day09::Tile;
+use crate::debug;
+
+impl Tile {
+    // area of rectangle defined by self and other point
+    fn area(&self, other: &Tile) -> u64 {
+        let width = (other.x as i64 - self.x as i64).unsigned_abs() + 1;
+        let height = (other.y as i64 - self.y as i64).unsigned_abs() + 1;
+        width * height
+    }
+}
+
+// brute force all possible pairs and scram them into a BTreeMap, which is sorted by distance
+fn area_map(points: &[Tile]) -> BTreeSet<u64> {
+    let mut rect_map: BTreeSet<u64> = BTreeSet::new();
+
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let area = points[i].area(&points[j]);
+            rect_map.insert(area);
+        }
+    }
+    debug!("......... created a map of {} pairs", rect_map.len());
+    rect_map
+}
+
+pub fn puzzle(vertices: &[Tile]) -> usize {
+    debug!("found {} points", vertices.len());
+
+    let rect_map = area_map(vertices);
+
+    // no rectangle without two tiles
+    rect_map.last().copied().unwrap_or_default() as usize
+}
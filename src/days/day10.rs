@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::advent_of_code::{Answer, InputError, Solution, column_of, parse_field};
+
+use super::day10_1;
+
+pub struct Day10;
+
+/// A machine of the factory, with the lights and the buttons as bitmasks, bit `i` for light `i`.
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct Machine {
+    /// The lights that have to be on.
+    pub lights: u32,
+    /// The lights toggled by each button.
+    pub buttons: Vec<u32>,
+    pub joltage: Vec<u32>,
+}
+
+impl fmt::Display for Machine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#08b} | {:?}", self.lights, self.buttons)
+    }
+}
+
+/// Parses one machine per line.
+pub fn parse(data: &[String]) -> Result<Vec<Machine>, InputError> {
+    data.iter()
+        .enumerate()
+        .map(|(index, line)| parse_machine(line, index))
+        .collect()
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<Machine>;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day10_1::puzzle(input).into()
+    }
+
+    // day10_2 is not finished yet, it panics on the example and the real input
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+// example line: "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"
+fn parse_machine(line: &str, index: usize) -> Result<Machine, InputError> {
+    let parts: Vec<&str> = line.split(' ').collect();
+    let [lights, buttons @ .., joltage] = parts.as_slice() else {
+        return Err(InputError::parse(
+            index,
+            0,
+            "expected lights, buttons and joltages",
+        ));
+    };
+
+    let lights = enclosed(line, lights, ('[', ']'), index)?;
+    if let Some(column) = lights.find(|c| c != '.' && c != '#') {
+        return Err(InputError::parse(
+            index,
+            column_of(line, lights) + column,
+            "lights are either '.' or '#'",
+        ));
+    }
+    // the lights are stored as a bitmask
+    if lights.len() > 32 {
+        return Err(InputError::parse(index, 0, "more than 32 lights"));
+    }
+    let lights_on = lights
+        .char_indices()
+        .filter(|&(_, c)| c == '#')
+        .fold(0, |mask, (i, _)| mask | 1 << i);
+
+    let mut masks = Vec::with_capacity(buttons.len());
+    for button in buttons {
+        let mut mask = 0;
+        for light in enclosed(line, button, ('(', ')'), index)?.split(',') {
+            let light_index = parse_field::<usize>(line, light, index)?;
+            if light_index >= lights.len() {
+                return Err(InputError::parse(
+                    index,
+                    column_of(line, light),
+                    format!("button toggles unknown light {}", light_index),
+                ));
+            }
+            mask |= 1 << light_index;
+        }
+        masks.push(mask);
+    }
+
+    let joltage = enclosed(line, joltage, ('{', '}'), index)?
+        .split(',')
+        .map(|level| parse_field(line, level, index))
+        .collect::<Result<_, _>>()?;
+    Ok(Machine {
+        lights: lights_on,
+        buttons: masks,
+        joltage,
+    })
+}
+
+// strips the brackets from `part`, a slice of `line`
+fn enclosed<'a>(
+    line: &str,
+    part: &'a str,
+    (open, close): (char, char),
+    index: usize,
+) -> Result<&'a str, InputError> {
+    part.strip_prefix(open)
+        .and_then(|p| p.strip_suffix(close))
+        .ok_or_else(|| {
+            InputError::parse(
+                index,
+                column_of(line, part),
+                format!("expected '{}...{}', found '{}'", open, close, part),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Machine, parse_machine};
+
+    #[test]
+    fn parse_machine_example() {
+        let line = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        assert_eq!(
+            parse_machine(line, 0).unwrap(),
+            Machine {
+                lights: 0b101110,
+                buttons: vec![0b11111, 0b11001, 0b110111, 0b110],
+                joltage: vec![10, 11, 11, 5, 10, 5],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_machine_reports_position() {
+        let err = parse_machine("[.##.] (3) (1,3) (2) 2,3 {3,5,4,7}", 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 22: expected '(...)', found '2,3'"
+        );
+        let err = parse_machine("[.##.] (3) (1,4) {3,5,4,7}", 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 15: button toggles unknown light 4"
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::day10::Machine;
+
+fn solve(machine: &Machine) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    // Start from state 0 with 0 operations
+    queue.push_back((0u32, 0usize));
+    visited.insert(0u32);
+
+    while let Some((state, ops)) = queue.pop_front() {
+        // Check if we reached target
+        if state == machine.lights {
+            return Some(ops);
+        }
+
+        // Try applying each tuple
+        for &button in machine.buttons.iter() {
+            let new_state = state ^ button; // XOR operation
+
+            if !visited.contains(&new_state) {
+                visited.insert(new_state);
+                queue.push_back((new_state, ops + 1));
+            }
+        }
+    }
+    None // No solution found
+}
+
+pub fn puzzle(machines: &[Machine]) -> usize {
+    machines.iter().map(|m| solve(m).unwrap_or_default()).sum()
+}
@@ -1,8 +1,8 @@
 use std::fmt;
 
 use nalgebra::{DMatrix, DVector};
-#[path = "../advent_of_code/mod.rs"]
-mod advent_of_code;
+
+use crate::trace;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Machine {
@@ -22,7 +22,7 @@ impl fmt::Display for Machine {
     }
 }
 
-fn fill_zeros(vec: &Vec<usize>, size: usize) -> Vec<usize> {
+fn fill_zeros(vec: &[usize], size: usize) -> Vec<usize> {
     let mut ret_vec = vec![0; size];
     vec.iter().for_each(|i| ret_vec[*i] = 1);
     ret_vec
@@ -73,14 +73,9 @@ fn solve(machine: &Machine) -> Option<usize> {
     let buttons_f64 = machine.buttons.map(|x| x as f64);
     let joltage_f64 = machine.joltage.map(|x| x as f64);
 
-    // let svd = buttons_f64.svd(true, true);
-    // let r = svd.solve(&joltage_f64, 1e-10);
-
-    // println!("SVD solution for machine with led {}: {:?}", machine.led, r);
-
     let qr = buttons_f64.qr();
     let r_qr = qr.solve(&joltage_f64);
-    println!(
+    trace!(
         "QR solution for machine with led {}: {:?}",
         machine.led, r_qr
     );
@@ -97,44 +92,7 @@ fn solve(machine: &Machine) -> Option<usize> {
 //     lu.solve(&target)
 // }
 
-fn puzzle(data: &Vec<String>) -> usize {
+pub fn puzzle(data: &[String]) -> usize {
     let machines: Vec<Machine> = data.iter().map(|line| Machine::from_str(line)).collect();
     machines.iter().map(|m| solve(m).unwrap_or_default()).sum()
 }
-
-fn main() {
-    let data = advent_of_code::Reader::read_file("./input/day10_test.txt").unwrap();
-
-    println!("parsed {} machines", data.len());
-    print!("machines:\n");
-    let machines: Vec<Machine> = data.iter().map(|line| Machine::from_str(line)).collect();
-    machines.iter().for_each(|m| println!("  {}", m));
-
-    let m = &machines[0];
-    println!("solving machine:\n  {}", m);
-    let result = solve(m).unwrap_or_default();
-    println!("result: {}", result);
-    // let result = puzzle(&data);
-    // println!("result: {}", result);
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{advent_of_code, puzzle};
-
-    #[test]
-    fn puzzle_test_data() {
-        let d = advent_of_code::Reader::read_file("./input/day10_test.txt").unwrap();
-        let result = puzzle(&d);
-        println!("result: {result}");
-        assert_eq!(result, 7);
-    }
-
-    #[test]
-    fn puzzle_final_data() {
-        let d = advent_of_code::Reader::read_file("./input/day10.txt").unwrap();
-        let result = puzzle(&d);
-        println!("result: {result}");
-        assert_eq!(result, 417);
-    }
-}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use crate::advent_of_code::{Answer, InputError, Solution, Variant, column_of};
+
+use super::{day11_1, day11_2};
+
+pub struct Day11;
+
+/// The outputs of every device.
+pub type Devices = HashMap<String, Vec<String>>;
+
+/// Parses one device with its outputs "aaa: bbb ccc" per line.
+///
+/// Every device is listed once and no output leads back to the device, so paths are finite.
+pub fn parse(data: &[String]) -> Result<Devices, InputError> {
+    let mut devices = Devices::new();
+    // line index of every device
+    let mut listed: HashMap<&str, usize> = HashMap::new();
+    for (index, line) in data.iter().enumerate() {
+        let (device, outputs) = match line.split_once(':') {
+            Some((device, outputs)) if !device.trim().is_empty() => (device.trim(), outputs),
+            _ => {
+                return Err(InputError::parse(
+                    index,
+                    0,
+                    "expected a device and its outputs 'aaa: bbb ccc'",
+                ));
+            }
+        };
+        if let Some(first) = listed.insert(device, index) {
+            return Err(InputError::parse(
+                index,
+                column_of(line, device),
+                format!(
+                    "device '{}' is listed in line {} already",
+                    device,
+                    first + 1
+                ),
+            ));
+        }
+        let outputs = outputs.split_whitespace().map(String::from).collect();
+        devices.insert(device.to_string(), outputs);
+    }
+
+    if let Some((device, output)) = find_cycle(&devices) {
+        let index = listed[device];
+        let line = &data[index];
+        let (_, outputs) = line.split_once(':').unwrap();
+        let field = outputs.split_whitespace().find(|o| *o == output).unwrap();
+        return Err(InputError::parse(
+            index,
+            column_of(line, field),
+            format!("output '{}' leads back to '{}'", output, device),
+        ));
+    }
+    Ok(devices)
+}
+
+// a device and the output of it that closes a cycle
+fn find_cycle(devices: &Devices) -> Option<(&str, &str)> {
+    // devices on the current path are `false`, finished ones `true`
+    let mut finished: HashMap<&str, bool> = HashMap::new();
+    let mut roots: Vec<&str> = devices.keys().map(String::as_str).collect();
+    roots.sort_unstable();
+    for root in roots {
+        if finished.contains_key(root) {
+            continue;
+        }
+        finished.insert(root, false);
+        // devices of the path with the index of their next output
+        let mut path = vec![(root, 0)];
+        while let Some(&(device, next)) = path.last() {
+            let outputs = devices.get(device).map_or(&[][..], Vec::as_slice);
+            let Some(output) = outputs.get(next) else {
+                finished.insert(device, true);
+                path.pop();
+                continue;
+            };
+            path.last_mut().unwrap().1 += 1;
+            match finished.get(output.as_str()) {
+                Some(false) => return Some((device, output)),
+                Some(true) => {}
+                None => {
+                    finished.insert(output, false);
+                    path.push((output, 0));
+                }
+            }
+        }
+    }
+    None
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Devices;
+
+    fn parse(&self, data: &[String]) -> Result<Self::Input, InputError> {
+        parse(data)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        day11_1::puzzle(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        day11_2::puzzle(input).into()
+    }
+
+    // part 2 starts at another device, which the first example does not have
+    fn example(&self, part: u32) -> Variant {
+        match part {
+            2 => Variant::Example2,
+            _ => Variant::Example,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::advent_of_code::lines;
+
+    #[test]
+    fn parse_devices() {
+        let devices = parse(&lines(&["you: aaa bbb", "aaa: out", "bbb: aaa out"])).unwrap();
+        assert_eq!(devices["bbb"], ["aaa", "out"]);
+        assert!(!devices.contains_key("out"));
+    }
+
+    #[test]
+    fn parse_rejects_duplicates_and_cycles() {
+        let error = |data: &[&str]| parse(&lines(data)).unwrap_err().to_string();
+        assert_eq!(
+            error(&["you: aaa", "aaa: out", "aaa: you"]),
+            "line 3, column 1: device 'aaa' is listed in line 2 already"
+        );
+        assert_eq!(
+            error(&["you: aaa", "aaa: bbb out", "bbb: you"]),
+            "line 1, column 6: output 'aaa' leads back to 'you'"
+        );
+        assert_eq!(
+            error(&["you: you"]),
+            "line 1, column 6: output 'you' leads back to 'you'"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use super::day11::Devices;
+use crate::debug;
+
+// Recursive function to count paths from current node to "out"
+// inspired by a Copilot suggestion
+fn count_paths(devices: &HashMap<String, Vec<String>>, current: &str) -> usize {
+    // Base case: reached target
+    if current == "out" {
+        return 1;
+    }
+    // Get outputs for current node
+    let Some(outputs) = devices.get(current) else {
+        return 0; // Dead end
+    };
+    // Sum paths through all outputs
+    outputs.iter().map(|next| count_paths(devices, next)).sum()
+}
+
+pub fn puzzle(devices: &Devices) -> usize {
+    debug!("parsed {} devices", devices.len());
+    count_paths(devices, "you")
+}
@@ -0,0 +1,52 @@
+use std::collections::{HashMap, HashSet};
+
+use super::day11::Devices;
+use crate::debug;
+
+// Recursive function to count paths from current node to "out"
+// inspired by a Copilot suggestion
+// cache explored nodes to avoid recomputation
+fn count_paths(
+    devices: &HashMap<String, Vec<String>>,
+    current: &str,
+    target: &str,
+    visited: &HashSet<String>, // Nodes visited on this path
+    cache: &mut HashMap<(String, bool, bool), usize>, // (node, has_dac, has_fft) -> count
+) -> usize {
+    let has_dac = visited.contains("dac");
+    let has_fft = visited.contains("fft");
+
+    // Base case: reached target and has both dac and fft
+    if current == target {
+        return if has_dac && has_fft { 1 } else { 0 };
+    }
+    // Check cache with state
+    let cache_key = (current.to_string(), has_dac, has_fft);
+    if let Some(&cached) = cache.get(&cache_key) {
+        return cached;
+    }
+    // Get outputs for current node
+    let Some(outputs) = devices.get(current) else {
+        cache.insert(cache_key, 0);
+        return 0; // Dead end
+    };
+
+    // Add current to visited set
+    let mut new_visited = visited.clone();
+    new_visited.insert(current.to_string());
+
+    // Sum paths through all outputs
+    let total: usize = outputs
+        .iter()
+        .map(|next| count_paths(devices, next, target, &new_visited, cache))
+        .sum();
+    cache.insert(cache_key, total);
+    total
+}
+
+pub fn puzzle(devices: &Devices) -> usize {
+    debug!("parsed {} devices", devices.len());
+    let mut cache = HashMap::new();
+    let visited = HashSet::new();
+    count_paths(devices, "svr", "out", &visited, &mut cache)
+}
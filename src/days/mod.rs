@@ -0,0 +1,82 @@
+pub mod day01;
+pub mod day01_1;
+pub mod day01_2;
+pub mod day02;
+pub mod day02_1;
+pub mod day02_2;
+pub mod day03;
+pub mod day03_1;
+pub mod day03_2;
+pub mod day04;
+pub mod day04_1;
+pub mod day04_2;
+pub mod day05;
+pub mod day05_1;
+pub mod day05_2;
+pub mod day06;
+pub mod day06_1;
+pub mod day06_2;
+pub mod day07;
+pub mod day07_1;
+pub mod day07_2;
+pub mod day08;
+pub mod day08_1;
+pub mod day09;
+pub mod day09_1;
+pub mod day09_2;
+pub mod day10;
+pub mod day10_1;
+pub mod day10_2;
+pub mod day11;
+pub mod day11_1;
+pub mod day11_2;
+
+use crate::advent_of_code::{Puzzle, Variant};
+
+/// All implemented days in ascending order.
+///
+/// `variant` selects the puzzle parameters of the examples, where they differ from the real input.
+pub fn registry(variant: Variant) -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day01::Day01 {
+            dial: day01::Dial::default(),
+        }),
+        Box::new(day02::Day02 { rule: None }),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08 {
+            connections: if variant.is_example() { 10 } else { 1000 },
+        }),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11),
+    ]
+}
+
+/// Looks up the puzzle of a single day.
+pub fn find(day: u32, variant: Variant) -> Option<Box<dyn Puzzle>> {
+    registry(variant)
+        .into_iter()
+        .find(|puzzle| puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, registry};
+    use crate::advent_of_code::Variant;
+
+    #[test]
+    fn registry_days_are_unique_and_sorted() {
+        let days: Vec<u32> = registry(Variant::Real).iter().map(|p| p.day()).collect();
+        assert_eq!(days, (1..=11).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn find_day() {
+        assert_eq!(find(4, Variant::Real).map(|p| p.day()), Some(4));
+        assert!(find(25, Variant::Real).is_none());
+    }
+}
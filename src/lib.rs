@@ -0,0 +1,4 @@
+//! Advent of Code 2025 solutions and the helpers shared between the days.
+
+pub mod advent_of_code;
+pub mod days;
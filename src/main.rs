@@ -1,3 +1,713 @@
-fn main() {
-    println!("Hello, world!");
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_2025::advent_of_code::bench::{self, Report, Stats};
+use aoc_2025::advent_of_code::log::{self, Level};
+use aoc_2025::advent_of_code::{
+    Answer, Answers, Check, Client, InputError, Puzzle, Reader, Variant, Verdict,
+};
+use aoc_2025::days::day01::{self, Day01, Dial, Trace};
+use aoc_2025::days::day02::{Day02, IdRule};
+use aoc_2025::{days, info};
+
+const USAGE: &str =
+    "usage: aoc_2025 [-v...] <day> <part> [--input <path>] [--example] [--rule <rule>]
+                     [--dial <dial>] [--trace <path>]
+       aoc_2025 [-v...] fetch <day>
+       aoc_2025 [-v...] submit <day> <part> [--wait]
+       aoc_2025 [-v...] bench [<day> [<part>]] [--runs <n>] [--output <path>]
+-v, -vv and -vvv log info, debug and trace output to stderr, as does AOC_LOG=info|debug|trace
+--rule replaces the invalid IDs of day 2 with exactly:<k>, at-least:<k> or palindrome,
+in another base with @<base>, e.g. at-least:3@16
+--dial replaces the dial of day 1 with <size>,<start>[,<target>...], e.g. 60,0,15,30,45
+--trace writes every click of the dial of day 1 to a CSV file";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Solve(Args),
+    /// Downloads the real input of the day, unless it is cached already.
+    Fetch {
+        day: u32,
+    },
+    /// Submits the answer for the real input, `wait` waits for the rate limit instead of giving up.
+    Submit {
+        day: u32,
+        part: u32,
+        wait: bool,
+    },
+    /// Times every part on the real input, or only those of `day` and `part`.
+    Bench {
+        day: Option<u32>,
+        part: Option<u32>,
+        runs: usize,
+        output: PathBuf,
+    },
+}
+
+const BENCH_RUNS: usize = 10;
+const BENCH_OUTPUT: &str = "bench.json";
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    day: u32,
+    part: u32,
+    input: Option<String>,
+    example: bool,
+    /// Rule for the invalid IDs of day 2.
+    rule: Option<IdRule>,
+    /// Dial of day 1.
+    dial: Option<Dial>,
+    /// Where to write the clicks of the dial of day 1.
+    trace: Option<PathBuf>,
+}
+
+fn parse_command(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("fetch") => {
+            args.next();
+            let [day] = args
+                .collect::<Vec<String>>()
+                .try_into()
+                .map_err(|_| "expected <day>")?;
+            Ok(Command::Fetch {
+                day: parse_day(&day)?,
+            })
+        }
+        Some("submit") => {
+            args.next();
+            let (flags, positional): (Vec<String>, Vec<String>) =
+                args.partition(|arg| arg.starts_with("--"));
+            let wait = match flags.as_slice() {
+                [] => false,
+                [flag] if flag == "--wait" => true,
+                _ => return Err(format!("unknown options: {}", flags.join(" "))),
+            };
+            let [day, part] = positional.as_slice() else {
+                return Err("expected <day> and <part>".to_string());
+            };
+            Ok(Command::Submit {
+                day: parse_day(day)?,
+                part: parse_part(part)?,
+                wait,
+            })
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench(args)
+        }
+        _ => parse_args(args).map(Command::Solve),
+    }
+}
+
+fn parse_bench(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut positional: Vec<String> = Vec::new();
+    let mut runs = BENCH_RUNS;
+    let mut output = PathBuf::from(BENCH_OUTPUT);
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--runs requires a positive number")?
+            }
+            "--output" => output = args.next().ok_or("--output requires a path")?.into(),
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, part) = match positional.as_slice() {
+        [] => (None, None),
+        [day] => (Some(parse_day(day)?), None),
+        [day, part] => (Some(parse_day(day)?), Some(parse_part(part)?)),
+        _ => return Err("expected at most <day> and <part>".to_string()),
+    };
+    Ok(Command::Bench {
+        day,
+        part,
+        runs,
+        output,
+    })
+}
+
+// takes the -v flags out of `args`, each v raises the log level by one
+fn take_verbosity(args: impl IntoIterator<Item = String>) -> (Option<Level>, Vec<String>) {
+    let (flags, args): (Vec<String>, Vec<String>) = args.into_iter().partition(|arg| {
+        arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v')
+    });
+    let count: usize = flags.iter().map(|flag| flag.len() - 1).sum();
+    let level = (count > 0).then(|| Level::from_verbosity(count));
+    (level, args)
+}
+
+fn parse_day(day: &str) -> Result<u32, String> {
+    day.parse::<u32>()
+        .map_err(|_| format!("invalid day: {}", day))
+}
+
+fn parse_part(part: &str) -> Result<u32, String> {
+    part.parse::<u32>()
+        .map_err(|_| format!("invalid part: {}", part))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut positional: Vec<String> = Vec::new();
+    let mut input = None;
+    let mut example = false;
+    let mut rule = None;
+    let mut dial = None;
+    let mut trace = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next() {
+                Some(path) => input = Some(path),
+                None => return Err("--input requires a path".to_string()),
+            },
+            "--example" => example = true,
+            "--rule" => match args.next() {
+                Some(name) => rule = Some(name.parse::<IdRule>()?),
+                None => return Err("--rule requires a rule like at-least:2".to_string()),
+            },
+            "--dial" => match args.next() {
+                Some(spec) => dial = Some(spec.parse::<Dial>()?),
+                None => return Err("--dial requires a dial like 100,50".to_string()),
+            },
+            "--trace" => match args.next() {
+                Some(path) => trace = Some(PathBuf::from(path)),
+                None => return Err("--trace requires a path".to_string()),
+            },
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let [day, part] = positional.as_slice() else {
+        return Err("expected <day> and <part>".to_string());
+    };
+    let day = parse_day(day)?;
+    let part = parse_part(part)?;
+    if rule.is_some() && day != 2 {
+        return Err("--rule only applies to day 2".to_string());
+    }
+    if (dial.is_some() || trace.is_some()) && day != 1 {
+        return Err("--dial and --trace only apply to day 1".to_string());
+    }
+
+    Ok(Args {
+        day,
+        part,
+        input,
+        example,
+        rule,
+        dial,
+        trace,
+    })
+}
+
+fn main() -> ExitCode {
+    let (level, args) = take_verbosity(std::env::args().skip(1));
+    if let Some(level) = level {
+        log::set_level(level);
+    }
+    match parse_command(args) {
+        Ok(Command::Solve(args)) => solve(&args),
+        Ok(Command::Fetch { day }) => fetch(day),
+        Ok(Command::Submit { day, part, wait }) => submit(day, part, wait),
+        Ok(Command::Bench {
+            day,
+            part,
+            runs,
+            output,
+        }) => run_bench(day, part, runs, &output),
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn fetch(day: u32) -> ExitCode {
+    let path = Reader::input_path(day, Variant::Real);
+    // a cached input needs no session token
+    if path.exists() {
+        println!("day {:02}: {} is cached", day, path.display());
+        return ExitCode::SUCCESS;
+    }
+    let result = Client::from_env().and_then(|client| {
+        info!("downloading from {}", client.base_url());
+        client.fetch_input(day, &path)
+    });
+    match result {
+        Ok(_) => {
+            println!("day {:02}: downloaded {}", day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn solve(args: &Args) -> ExitCode {
+    // the puzzle knows which example belongs to the part
+    let variant = match args.example {
+        false => Variant::Real,
+        true => days::find(args.day, Variant::Example)
+            .map_or(Variant::Example, |puzzle| puzzle.example(args.part)),
+    };
+    let path = match &args.input {
+        Some(path) => PathBuf::from(path),
+        None => Reader::input_path(args.day, variant),
+    };
+    let puzzle = match (args.rule, &args.dial) {
+        (Some(rule), _) => Some(Box::new(Day02 { rule: Some(rule) }) as Box<dyn Puzzle>),
+        (_, Some(dial)) => Some(Box::new(Day01 { dial: dial.clone() }) as Box<dyn Puzzle>),
+        (None, None) => days::find(args.day, variant),
+    };
+    let (answer, duration) = match compute(puzzle, args.day, args.part, &path) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // answers of inputs given by path, of other rules or of other dials are unknown
+    let custom = args.input.is_some() || args.rule.is_some() || args.dial.is_some();
+    let check = match custom {
+        true => None,
+        false => match load_answers() {
+            Ok(answers) => Some(answers.check(args.day, args.part, variant, &answer)),
+            Err(e) => {
+                eprintln!("error: {}: {}", Answers::path().display(), e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    match &check {
+        Some(check) => println!(
+            "day {:02} part {}: {} ({})",
+            args.day, args.part, answer, check
+        ),
+        None => println!("day {:02} part {}: {}", args.day, args.part, answer),
+    }
+    println!("Duration: {:?}", duration);
+    if let Some(output) = &args.trace {
+        let dial = args.dial.clone().unwrap_or_default();
+        match write_trace(&dial, &path, output) {
+            Ok(clicks) => println!("{} clicks written to {}", clicks, output.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    match check {
+        Some(Check::Wrong { .. } | Check::Rejected(_)) => ExitCode::FAILURE,
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+// solves the part on the input at `path`, the error is the message for the user
+fn compute(
+    puzzle: Option<Box<dyn Puzzle>>,
+    day: u32,
+    part: u32,
+    path: &Path,
+) -> Result<(Answer, Duration), String> {
+    let puzzle = puzzle.ok_or(format!("day {} is not implemented", day))?;
+    info!("reading {}", path.display());
+    let data = Reader::read_file(path).map_err(|e| format!("error: {}", e))?;
+
+    let start_time = Instant::now();
+    match puzzle.solve(part, &data) {
+        Ok(Answer::Unsolved) => Err(format!("day {} part {} is not implemented", day, part)),
+        Ok(answer) => Ok((answer, start_time.elapsed())),
+        Err(e) => Err(format!("error: {}: {}", path.display(), e)),
+    }
+}
+
+// writes every click of `dial` turned by the rotations at `path` to `output`, returns the clicks
+fn write_trace(dial: &Dial, path: &Path, output: &Path) -> Result<usize, String> {
+    let data = Reader::read_file(path).map_err(|e| format!("error: {}", e))?;
+    let rotations = day01::parse(&data).map_err(|e| format!("error: {}: {}", path.display(), e))?;
+    let trace = Trace::record(dial, &rotations);
+    trace
+        .write_csv(output)
+        .map_err(|e| format!("error: writing {}: {}", output.display(), e))?;
+    // the first step is the start position
+    Ok(trace.steps().len() - 1)
+}
+
+fn submit(day: u32, part: u32, wait: bool) -> ExitCode {
+    let path = Reader::input_path(day, Variant::Real);
+    let puzzle = days::find(day, Variant::Real);
+    let answer = match compute(puzzle, day, part, &path) {
+        Ok((answer, _)) => answer,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut answers = match load_answers() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}: {}", Answers::path().display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let name = format!("day {:02} part {}", day, part);
+    // the server only needs to be asked about answers that are not known yet
+    match answers.check(day, part, Variant::Real, &answer) {
+        Check::Correct => {
+            println!("{}: {} is correct already, not submitting", name, answer);
+            return ExitCode::SUCCESS;
+        }
+        Check::Wrong { expected } => {
+            eprintln!(
+                "{}: {} is wrong, the answer is {}, not submitting",
+                name, answer, expected
+            );
+            return ExitCode::FAILURE;
+        }
+        Check::Rejected(rejection) => {
+            eprintln!(
+                "{}: {} is known to be {}, not submitting",
+                name, answer, rejection
+            );
+            return ExitCode::FAILURE;
+        }
+        _ => {}
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    // wait out the interval after the last submission before asking the server at all
+    let pause = client.submit_wait();
+    if !pause.is_zero() {
+        if !wait {
+            eprintln!(
+                "{}: an answer was submitted too recently, submit again in {}s or pass --wait",
+                name,
+                pause.as_secs()
+            );
+            return ExitCode::FAILURE;
+        }
+        println!(
+            "{}: an answer was submitted too recently, submitting in {}s",
+            name,
+            pause.as_secs()
+        );
+        std::thread::sleep(pause);
+    }
+    let verdict = loop {
+        match client.submit(day, part, &answer) {
+            Ok(Verdict::RateLimited {
+                wait: Some(duration),
+            }) if wait => {
+                println!(
+                    "{}: rate limited, submitting again in {}s",
+                    name,
+                    duration.as_secs()
+                );
+                std::thread::sleep(duration + Duration::from_secs(1));
+            }
+            Ok(verdict) => break verdict,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    println!("{}: {} ({})", name, answer, verdict);
+
+    match verdict {
+        Verdict::Correct => answers.insert(day, part, Variant::Real, answer),
+        Verdict::Rejected(rejection) => answers.reject(day, part, &answer, rejection),
+        _ => return ExitCode::FAILURE,
+    }
+    if let Err(e) = answers.save(Answers::path()) {
+        eprintln!("error: writing {}: {}", Answers::path().display(), e);
+        return ExitCode::FAILURE;
+    }
+    match verdict {
+        Verdict::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn run_bench(day: Option<u32>, part: Option<u32>, runs: usize, output: &Path) -> ExitCode {
+    let puzzles: Vec<_> = days::registry(Variant::Real)
+        .into_iter()
+        .filter(|puzzle| day.is_none_or(|day| puzzle.day() == day))
+        .collect();
+    if puzzles.is_empty() {
+        eprintln!("day {} is not implemented", day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    let mut report = Report::default();
+    for puzzle in puzzles {
+        let data = match Reader::read_input(puzzle.day(), Variant::Real) {
+            Ok(data) => data,
+            // inputs are not part of every checkout
+            Err(InputError::Missing { path }) => {
+                println!(
+                    "day {:02}: skipped, {} is missing",
+                    puzzle.day(),
+                    path.display()
+                );
+                continue;
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        for part in parts.clone() {
+            match bench::measure(puzzle.as_ref(), part, &data, runs) {
+                Ok(Some(measurement)) => {
+                    println!(
+                        "day {:02} part {}: parse {}, solve {}",
+                        measurement.day,
+                        measurement.part,
+                        format_stats(&measurement.parse),
+                        format_stats(&measurement.solve)
+                    );
+                    report.measurements.push(measurement);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("error: day {} part {}: {}", puzzle.day(), part, e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if let Err(e) = std::fs::write(output, report.to_json()) {
+        eprintln!("error: writing {}: {}", output.display(), e);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "min / median / max of {} runs written to {}",
+        runs,
+        output.display()
+    );
+    ExitCode::SUCCESS
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{:.1?} / {:.1?} / {:.1?}",
+        stats.min, stats.median, stats.max
+    )
+}
+
+// a missing answers.toml is the same as an empty one
+fn load_answers() -> Result<Answers, InputError> {
+    match Answers::load(Answers::path()) {
+        Err(InputError::Missing { .. }) => Ok(Answers::default()),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Args, Command, Dial, Level, parse_args, parse_command, take_verbosity, write_trace,
+    };
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_args_day_and_part() {
+        let result = parse_args(args("4 2")).unwrap();
+        assert_eq!(
+            result,
+            Args {
+                day: 4,
+                part: 2,
+                input: None,
+                example: false,
+                rule: None,
+                dial: None,
+                trace: None
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_options() {
+        let result = parse_args(args("--example 11 --input foo.txt 2")).unwrap();
+        assert_eq!(result.input, Some("foo.txt".to_string()));
+        assert!(result.example);
+        assert_eq!((result.day, result.part), (11, 2));
+    }
+
+    #[test]
+    fn parse_command_fetch() {
+        assert_eq!(
+            parse_command(args("fetch 7")).unwrap(),
+            Command::Fetch { day: 7 }
+        );
+        assert!(matches!(
+            parse_command(args("7 1")).unwrap(),
+            Command::Solve(Args {
+                day: 7,
+                part: 1,
+                ..
+            })
+        ));
+        assert!(parse_command(args("fetch")).is_err());
+        assert!(parse_command(args("fetch 7 1")).is_err());
+        assert!(parse_command(args("fetch seven")).is_err());
+    }
+
+    #[test]
+    fn parse_command_submit() {
+        assert_eq!(
+            parse_command(args("submit 7 2")).unwrap(),
+            Command::Submit {
+                day: 7,
+                part: 2,
+                wait: false
+            }
+        );
+        assert_eq!(
+            parse_command(args("submit --wait 7 1")).unwrap(),
+            Command::Submit {
+                day: 7,
+                part: 1,
+                wait: true
+            }
+        );
+        assert!(parse_command(args("submit 7")).is_err());
+        assert!(parse_command(args("submit 7 1 --example")).is_err());
+    }
+
+    #[test]
+    fn parse_command_bench() {
+        assert_eq!(
+            parse_command(args("bench")).unwrap(),
+            Command::Bench {
+                day: None,
+                part: None,
+                runs: 10,
+                output: "bench.json".into()
+            }
+        );
+        assert_eq!(
+            parse_command(args("bench 4 2 --runs 3 --output out.json")).unwrap(),
+            Command::Bench {
+                day: Some(4),
+                part: Some(2),
+                runs: 3,
+                output: "out.json".into()
+            }
+        );
+        assert!(parse_command(args("bench --runs 0")).is_err());
+        assert!(parse_command(args("bench 4 2 1")).is_err());
+    }
+
+    #[test]
+    fn take_verbosity_flags() {
+        assert_eq!(take_verbosity(args("4 1")), (None, args("4 1")));
+        assert_eq!(
+            take_verbosity(args("-v 4 1")),
+            (Some(Level::Info), args("4 1"))
+        );
+        assert_eq!(
+            take_verbosity(args("bench -vv 4 -v")),
+            (Some(Level::Trace), args("bench 4"))
+        );
+        assert_eq!(take_verbosity(args("- -x")), (None, args("- -x")));
+    }
+
+    #[test]
+    fn parse_args_rule() {
+        let result = parse_args(args("2 1 --rule exactly:3@16")).unwrap();
+        assert_eq!(
+            result.rule.map(|rule| rule.to_string()),
+            Some("exactly:3@16".into())
+        );
+        assert!(parse_args(args("2 1 --rule")).is_err());
+        assert_eq!(
+            parse_args(args("2 1 --rule twice")).unwrap_err(),
+            "unknown rule 'twice', expected exactly:<k>, at-least:<k> or palindrome"
+        );
+        assert_eq!(
+            parse_args(args("4 1 --rule palindrome")).unwrap_err(),
+            "--rule only applies to day 2"
+        );
+    }
+
+    #[test]
+    fn parse_args_dial_and_trace() {
+        let result = parse_args(args("1 2 --dial 10,3,5 --trace clicks.csv")).unwrap();
+        assert_eq!(result.dial, Some(Dial::new(10, 3).with_targets([5])));
+        assert_eq!(result.trace, Some("clicks.csv".into()));
+        assert!(parse_args(args("1 2 --dial")).is_err());
+        assert!(parse_args(args("1 2 --trace")).is_err());
+        assert_eq!(
+            parse_args(args("1 2 --dial 10,10")).unwrap_err(),
+            "start 10 is not on a dial of size 10"
+        );
+        assert_eq!(
+            parse_args(args("2 1 --trace clicks.csv")).unwrap_err(),
+            "--dial and --trace only apply to day 1"
+        );
+    }
+
+    #[test]
+    fn write_trace_of_the_input() {
+        let dir = std::env::temp_dir();
+        let input = dir.join(format!("aoc_2025_rotations_{}.txt", std::process::id()));
+        let output = dir.join(format!("aoc_2025_trace_{}.csv", std::process::id()));
+        std::fs::write(&input, "L2\nR1\n").unwrap();
+        let clicks = write_trace(&Dial::new(4, 1), &input, &output);
+        let csv = std::fs::read_to_string(&output);
+        std::fs::remove_file(&input).unwrap();
+        std::fs::remove_file(&output).unwrap();
+
+        assert_eq!(clicks, Ok(3));
+        assert_eq!(
+            csv.unwrap(),
+            "rotation,instruction,click,position,on_target\n\
+             0,,0,1,false\n\
+             1,L2,1,0,true\n\
+             1,L2,2,3,false\n\
+             2,R1,1,0,true\n"
+        );
+    }
+
+    #[test]
+    fn parse_args_errors() {
+        assert!(parse_args(args("4")).is_err());
+        assert!(parse_args(args("four 1")).is_err());
+        assert!(parse_args(args("4 1 --input")).is_err());
+        assert!(parse_args(args("4 1 --verbose")).is_err());
+    }
 }
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use aoc_2025::advent_of_code::{self, Grid, InputError, LimitedVecDeque, RingBuffer, Variant};
+
+fn lines(s: &str) -> Vec<String> {
+    s.lines().map(String::from).collect()
+}
+
+#[test]
+fn reader_reads_all_lines() {
+    let d = advent_of_code::Reader::read_input(4, Variant::Example).unwrap();
+    assert_eq!(d.len(), 10);
+    assert!(d.iter().all(|line| line.len() == 10));
+}
+
+#[test]
+fn reader_missing_file() {
+    let err = advent_of_code::Reader::read_input(99, Variant::Example).unwrap_err();
+    assert!(matches!(err, InputError::Missing { .. }));
+    let path = advent_of_code::Reader::input_path(99, Variant::Example);
+    assert_eq!(
+        err.to_string(),
+        format!("input file {} not found", path.display())
+    );
+}
+
+#[test]
+fn reader_reports_non_utf8_line() {
+    let path = std::env::temp_dir().join(format!("aoc_2025_not_utf8_{}.txt", std::process::id()));
+    std::fs::write(&path, b"fine\r\nstill fine\nbro\xffken\n").unwrap();
+    let err = advent_of_code::Reader::read_file(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(err, InputError::NotUtf8 { line: 3, .. }));
+}
+
+#[test]
+fn reader_strips_line_endings() {
+    let path = std::env::temp_dir().join(format!("aoc_2025_crlf_{}.txt", std::process::id()));
+    std::fs::write(&path, "a\r\nb\nc").unwrap();
+    let d = advent_of_code::Reader::read_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(d, ["a", "b", "c"]);
+}
+
+#[test]
+fn grid_positions_and_neighbours() {
+    let grid = Grid::from_lines(&lines("@.@\n.@.\n@..")).unwrap();
+    assert_eq!((grid.rows(), grid.cols()), (3, 3));
+    assert_eq!(
+        grid.find_char_positions('@'),
+        HashSet::from([(0, 0), (0, 2), (1, 1), (2, 0)])
+    );
+    assert_eq!(grid.get_surrounding_positions(0, 0).len(), 3);
+    assert_eq!(grid.get_surrounding_positions(1, 1).len(), 8);
+    let at_count = grid
+        .get_surrounding_chars(1, 1)
+        .iter()
+        .filter(|&&c| c == '@')
+        .count();
+    assert_eq!(at_count, 3);
+}
+
+#[test]
+fn limited_vec_deque_drops_oldest() {
+    let mut deque = LimitedVecDeque::new(2);
+    assert!(deque.is_empty());
+    deque.push(1);
+    deque.push(2);
+    deque.push(3);
+    assert_eq!(deque.len(), 2);
+    assert_eq!(deque.as_deque().iter().copied().collect::<Vec<_>>(), [2, 3]);
+}
+
+#[test]
+fn ring_buffer_cycles() {
+    let ring = RingBuffer::new(vec!['a', 'b', 'c']);
+    assert_eq!(ring.take(5).collect::<String>(), "abcab");
+    assert_eq!(RingBuffer::<char>::new(vec![]).next(), None);
+}
+
+#[test]
+fn matrix_and_find_char() {
+    let matrix = advent_of_code::lines_to_matrix(&lines("ab\ncd\nef"));
+    assert_eq!(matrix, vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']]);
+    assert_eq!(advent_of_code::find_char(matrix, 'd'), (1, 1));
+}
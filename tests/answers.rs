@@ -0,0 +1,107 @@
+use aoc_2025::advent_of_code::{Answers, Check, InputError, Reader, Variant};
+use aoc_2025::days;
+
+const VARIANTS: [Variant; 3] = [Variant::Example, Variant::Example2, Variant::Real];
+
+// Solves both parts of `day` on every input it has and compares the answers with answers.toml.
+// Wrong, rejected and missing answers fail the test, new answers and missing input files are only reported.
+fn check_day(day: u32) {
+    let answers = Answers::load(Answers::path()).unwrap();
+    let mut report = Vec::new();
+    let mut failed = false;
+
+    for part in 1..=2 {
+        for variant in VARIANTS {
+            let puzzle = days::find(day, variant).unwrap();
+            let expected = answers.get(day, part, variant);
+            // examples that belong to the other part are not checked, unless they have an answer
+            if variant.is_example() && puzzle.example(part) != variant && expected.is_none() {
+                continue;
+            }
+
+            let name = format!("day {:02} part {} {}", day, part, variant.name());
+            let data = match Reader::read_input(day, variant) {
+                Ok(data) => data,
+                Err(InputError::Missing { path }) => {
+                    if let Some(expected) = expected {
+                        report.push(format!(
+                            "{}: input {} is missing, expected {}",
+                            name,
+                            path.display(),
+                            expected
+                        ));
+                    }
+                    continue;
+                }
+                Err(e) => panic!("{}: {}", name, e),
+            };
+            let answer = puzzle
+                .solve(part, &data)
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+            let check = answers.check(day, part, variant, &answer);
+            failed |= matches!(
+                check,
+                Check::Wrong { .. } | Check::Rejected(_) | Check::Missing { .. }
+            );
+            if check != Check::Unsolved {
+                report.push(format!("{}: {} ({})", name, answer, check));
+            }
+        }
+    }
+
+    println!("{}", report.join("\n"));
+    assert!(!failed, "answers of day {} differ from answers.toml", day);
+}
+
+// one test per day, so a wrong day is named by the failing test
+macro_rules! answer_tests {
+    ($($name:ident: $day:expr),* $(,)?) => {
+        const DAYS: &[u32] = &[$($day),*];
+
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+}
+
+#[test]
+fn every_day_is_checked() {
+    let registered: Vec<u32> = days::registry(Variant::Real)
+        .iter()
+        .map(|puzzle| puzzle.day())
+        .collect();
+    assert_eq!(registered, DAYS);
+}
+
+#[test]
+fn answers_belong_to_known_days() {
+    let answers = Answers::load(Answers::path()).unwrap();
+    for ((day, part, variant), answer) in answers.iter() {
+        assert!(
+            DAYS.contains(&day),
+            "answers.toml: day {} part {} {} = {} belongs to an unknown day",
+            day,
+            part,
+            variant.name(),
+            answer
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aoc_2025::advent_of_code::{Answer, Client, ClientError, Rejection, Verdict};
+
+// Stand-in for adventofcode.com answering every request with `status` and `body`.
+// Returns the base URL and every request received so far.
+fn serve(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/2025", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let head: Vec<String> = (&mut reader)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            let length = head
+                .iter()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            received.lock().unwrap().push(format!(
+                "{}\n\n{}",
+                head.join("\n"),
+                String::from_utf8(content).unwrap()
+            ));
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+    (base_url, requests)
+}
+
+fn temp_input(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("aoc_2025_fetch_{}_{}", name, std::process::id()))
+        .join("day03.txt")
+}
+
+#[test]
+fn fetch_input_downloads_once() {
+    let (base_url, requests) = serve("200 OK", "987654321111111\n811111111111119\n");
+    let client = Client::new(&base_url, "secret");
+    let path = temp_input("once");
+
+    assert!(client.fetch_input(3, &path).unwrap());
+    assert!(!client.fetch_input(3, &path).unwrap());
+    let input = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+    assert_eq!(input, "987654321111111\n811111111111119\n");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1"));
+    assert!(requests[0].contains("session=secret"), "{}", requests[0]);
+}
+
+#[test]
+fn fetch_input_does_not_cache_errors() {
+    let (base_url, _) = serve(
+        "400 Bad Request",
+        "Please don't repeatedly request this endpoint",
+    );
+    let client = Client::new(&base_url, "expired");
+    let path = temp_input("error");
+
+    let err = client.fetch_input(3, &path).unwrap_err();
+    assert!(
+        matches!(err, ClientError::Status { status: 400, .. }),
+        "{}",
+        err
+    );
+    assert!(!path.exists());
+}
+
+#[test]
+fn submit_posts_the_answer() {
+    let (base_url, requests) = serve(
+        "200 OK",
+        "<html><main><article><p>That's not the right answer; your answer is too low.</p></article></main></html>",
+    );
+    let client = Client::new(&base_url, "secret");
+
+    let verdict = client.submit(3, 2, &Answer::Number(1234)).unwrap();
+    assert_eq!(verdict, Verdict::Rejected(Rejection::TooLow));
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("POST /2025/day/3/answer HTTP/1.1"));
+    assert!(requests[0].contains("session=secret"));
+    assert!(
+        requests[0].ends_with("\n\nlevel=2&answer=1234"),
+        "{}",
+        requests[0]
+    );
+}
+
+#[test]
+fn submit_records_when_to_submit_next() {
+    let file = std::env::temp_dir().join(format!("aoc_2025_next_submit_{}", std::process::id()));
+    let (base_url, _) = serve(
+        "200 OK",
+        "<html><main><article><p>That's not the right answer.</p></article></main></html>",
+    );
+    let client = Client::new(&base_url, "secret").with_submit_file(file.clone());
+    assert_eq!(client.submit_wait(), Duration::ZERO);
+    client.submit(3, 1, &Answer::Number(1234)).unwrap();
+    let after_wrong = client.submit_wait();
+
+    // the server's wait replaces the interval, also for other clients
+    let (base_url, _) = serve(
+        "200 OK",
+        "<html><main><article><p>You gave an answer too recently. You have 5m 10s left to wait.</p></article></main></html>",
+    );
+    let limited = Client::new(&base_url, "secret").with_submit_file(file.clone());
+    limited.submit(3, 1, &Answer::Number(1234)).unwrap();
+    let after_limit = client.submit_wait();
+    std::fs::remove_file(&file).unwrap();
+
+    assert!(
+        after_wrong > Client::SUBMIT_INTERVAL - Duration::from_secs(5)
+            && after_wrong <= Client::SUBMIT_INTERVAL + Duration::from_secs(1),
+        "{:?}",
+        after_wrong
+    );
+    assert!(after_limit > Duration::from_secs(300), "{:?}", after_limit);
+    // without a file nothing is throttled
+    assert_eq!(
+        Client::new(&base_url, "secret").submit_wait(),
+        Duration::ZERO
+    );
+}